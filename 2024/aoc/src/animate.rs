@@ -0,0 +1,355 @@
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+use std::io::{self, BufRead, Write};
+use std::thread;
+
+use crate::{Grid, Point};
+
+const CLEAR: &str = "\x1b[2J\x1b[H";
+const RESET: &str = "\x1b[0m";
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    #[default]
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    /// Colours that are easy to tell apart, for things like file ids that only need to differ from their neighbours.
+    pub const PALETTE: [Colour; 6] = [Colour::Red, Colour::Green, Colour::Yellow, Colour::Blue, Colour::Magenta, Colour::Cyan];
+
+    fn escape(&self) -> &'static str {
+        match self {
+            Colour::Default => "\x1b[39m",
+            Colour::Red => "\x1b[31m",
+            Colour::Green => "\x1b[32m",
+            Colour::Yellow => "\x1b[33m",
+            Colour::Blue => "\x1b[34m",
+            Colour::Magenta => "\x1b[35m",
+            Colour::Cyan => "\x1b[36m",
+            Colour::White => "\x1b[97m",
+            Colour::Grey => "\x1b[90m",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Colour,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, colour: Colour) -> Cell {
+        Cell {
+            glyph,
+            colour,
+        }
+    }
+}
+
+/// One picture of a simulation, a grid of coloured glyphs with a caption underneath.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+    caption: String,
+}
+
+impl Frame {
+    /// Draws every cell of `grid` with `f`, overlays go on top afterwards.
+    pub fn new<T, F>(grid: &Grid<T>, f: F) -> Frame
+    where
+        F: FnMut(&T) -> Cell,
+    {
+        Frame {
+            cells: grid.map(f),
+            caption: String::new(),
+        }
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    /// Recolours the cells under `points` and keeps their glyphs, points outside of the frame are ignored.
+    pub fn paint(mut self, points: impl IntoIterator<Item = Point>, colour: Colour) -> Frame {
+        for point in points {
+            if let Some(cell) = self.cells.get_mut(point) {
+                cell.colour = colour;
+            }
+        }
+
+        self
+    }
+
+    /// Replaces the cell under `point`, like the guard on top of the map.
+    pub fn mark(mut self, point: Point, cell: Cell) -> Frame {
+        if let Some(under) = self.cells.get_mut(point) {
+            *under = cell;
+        }
+
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// The frame as text, with colour escapes if `ansi` is set.
+    pub fn render(&self, ansi: bool) -> String {
+        let mut text = String::new();
+
+        for row in self.cells.rows() {
+            let mut colour = Colour::Default;
+
+            for cell in row {
+                if ansi && cell.colour != colour {
+                    colour = cell.colour;
+                    text.push_str(colour.escape());
+                }
+
+                text.push(cell.glyph);
+            }
+
+            if ansi && colour != Colour::Default {
+                text.push_str(RESET);
+            }
+
+            text.push('\n');
+        }
+
+        if !self.caption.is_empty() {
+            text.push_str(&self.caption);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// A simulation that can be watched, every call advances it by one step.
+pub trait Animation {
+    /// The next frame, `None` once the simulation is over.
+    fn frame(&mut self) -> Option<Frame>;
+}
+
+/// What the viewer typed, one command per line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Shows a single frame and pauses.
+    Step,
+    /// Pauses or resumes.
+    Toggle,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    /// A bare enter steps while paused and pauses while playing.
+    pub fn parse(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" | "s" => Some(Control::Step),
+            "p" | " " => Some(Control::Toggle),
+            "+" | "f" => Some(Control::Faster),
+            "-" | "d" => Some(Control::Slower),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Reads controls from stdin on a background thread, the terminal hands them over a line at a time.
+pub fn controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+
+            if let Some(control) = Control::parse(&line) {
+                if sender.send(control).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    receiver
+}
+
+/// Shows the frames of an animation one after the other.
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub delay: Duration,
+    pub paused: bool,
+    /// Colours and redraws in place, otherwise frames are printed plainly one below the other.
+    pub ansi: bool,
+}
+
+impl Player {
+    pub const MIN_DELAY: Duration = Duration::from_millis(1);
+
+    pub fn new(fps: f64) -> Player {
+        Player {
+            delay: Duration::from_secs_f64(1.0 / fps.max(0.001)).max(Player::MIN_DELAY),
+            paused: false,
+            ansi: true,
+        }
+    }
+
+    fn apply(&mut self, control: Control) {
+        match control {
+            Control::Step => self.paused = true,
+            Control::Toggle => self.paused = !self.paused,
+            Control::Faster => self.delay = (self.delay / 2).max(Player::MIN_DELAY),
+            Control::Slower => self.delay *= 2,
+            Control::Quit => {},
+        }
+    }
+
+    fn status(&self, shown: usize) -> String {
+        let state = if self.paused { "paused, enter steps, p resumes" } else { "playing, enter pauses" };
+
+        format!("frame {} | {} | {} per frame, +/- to change, q quits", shown, state, crate::time::format(self.delay))
+    }
+
+    /// Waits for the next frame to be due, `false` once the viewer quit.
+    fn wait(&mut self, controls: &Receiver<Control>) -> bool {
+        loop {
+            let received = if self.paused {
+                controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                controls.recv_timeout(self.delay)
+            };
+
+            match received {
+                Ok(Control::Quit) => return false,
+                // a step while paused shows exactly one frame
+                Ok(Control::Step) if self.paused => return true,
+                Ok(control) => self.apply(control),
+                Err(RecvTimeoutError::Timeout) => return true,
+                // nobody is steering, so play everything at the current speed
+                Err(RecvTimeoutError::Disconnected) => {
+                    self.paused = false;
+                    thread::sleep(self.delay);
+
+                    return true;
+                },
+            }
+        }
+    }
+
+    /// Plays `animation` to its end or until the viewer quits, returns how many frames were shown.
+    pub fn play<W: Write>(&mut self, animation: &mut dyn Animation, out: &mut W, controls: &Receiver<Control>) -> io::Result<usize> {
+        let mut shown = 0;
+
+        while let Some(frame) = animation.frame() {
+            shown += 1;
+
+            if self.ansi {
+                writeln!(out, "{}{}{}", CLEAR, frame.render(true), self.status(shown))?;
+            } else {
+                writeln!(out, "{}", frame.render(false))?;
+            }
+
+            out.flush()?;
+
+            if !self.wait(controls) {
+                break;
+            }
+        }
+
+        Ok(shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter {
+        count: usize,
+        limit: usize,
+    }
+
+    impl Animation for Counter {
+        fn frame(&mut self) -> Option<Frame> {
+            if self.count == self.limit {
+                return None;
+            }
+
+            self.count += 1;
+
+            let grid = Grid::new(self.limit, 1, (0..self.limit).collect());
+
+            Some(Frame::new(&grid, |_| Cell::new('.', Colour::Default)).paint((0..self.count).map(|x| Point::from_grid(x, 0)), Colour::Green))
+        }
+    }
+
+    fn player() -> Player {
+        Player {
+            delay: Player::MIN_DELAY,
+            paused: false,
+            ansi: false,
+        }
+    }
+
+    #[test]
+    fn render() {
+        let grid = Grid::chars("ab\ncd\n").unwrap();
+        let frame = Frame::new(&grid, |c| Cell::new(*c, Colour::Default))
+            .paint([Point::new(1, 0), Point::new(5, 5)], Colour::Red)
+            .mark(Point::new(0, 1), Cell::new('^', Colour::Red))
+            .caption("step 1");
+
+        assert_eq!(frame.render(false), "ab\n^d\nstep 1\n");
+        assert_eq!(frame.render(true), "a\x1b[31mb\x1b[0m\n\x1b[31m^\x1b[39md\nstep 1\n");
+    }
+
+    #[test]
+    fn controls() {
+        assert_eq!(Control::parse("\r"), Some(Control::Step));
+        assert_eq!(Control::parse("+"), Some(Control::Faster));
+        assert_eq!(Control::parse("x"), None);
+
+        let mut player = Player::new(10.0);
+
+        player.apply(Control::Faster);
+        assert_eq!(player.delay, Duration::from_millis(50));
+
+        player.apply(Control::Toggle);
+        assert!(player.paused);
+    }
+
+    #[test]
+    fn play() {
+        let (sender, receiver) = mpsc::channel();
+        let mut out = Vec::new();
+
+        drop(sender);
+
+        assert_eq!(player().play(&mut Counter { count: 0, limit: 3 }, &mut out, &receiver).unwrap(), 3);
+        assert_eq!(String::from_utf8(out).unwrap(), "...\n\n...\n\n...\n\n");
+
+        let (sender, receiver) = mpsc::channel();
+
+        sender.send(Control::Step).unwrap();
+        sender.send(Control::Step).unwrap();
+        sender.send(Control::Quit).unwrap();
+
+        assert_eq!(player().play(&mut Counter { count: 0, limit: 10 }, &mut Vec::new(), &receiver).unwrap(), 2);
+    }
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+use std::fs;
+
+use crate::{Part, ParseError};
+
+pub const FILE: &str = "answers.txt";
+
+
+/// The known answers of a day, one `<input> <part> <answer>` line each.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    entries: Vec<(String, Part, String)>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Answers, ParseError> {
+        let entries = content.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let mut fields = line.splitn(3, char::is_whitespace);
+
+                let input = fields.next().unwrap_or_default();
+                let part = fields.next().ok_or_else(|| ParseError::at(content, line, "expected `<input> <part> <answer>`"))?;
+                let answer = fields.next().map(|answer| answer.trim()).filter(|answer| !answer.is_empty())
+                    .ok_or_else(|| ParseError::at(content, line, "expected `<input> <part> <answer>`"))?;
+
+                Ok((input.to_string(), crate::error::parse::<Part>(content, part, "part")?, answer.to_string()))
+            })
+            .collect::<Result<Vec<(String, Part, String)>, ParseError>>()?;
+
+        Ok(Answers {
+            entries,
+        })
+    }
+
+    /// Loads the answers stored at `path`, a missing file has no answers yet.
+    pub fn load(path: &Path) -> crate::Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        Answers::parse(&fs::read_to_string(path)?)
+            .map_err(|err| err.with_file(&path.display().to_string()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> crate::Result<()> {
+        fs::write(path, self.to_string())?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.entries.iter()
+            .find(|(name, entry, _)| name == input && *entry == part)
+            .map(|(_, _, answer)| answer.as_str())
+    }
+
+    pub fn set(&mut self, input: &str, part: Part, answer: &str) {
+        match self.entries.iter_mut().find(|(name, entry, _)| name == input && *entry == part) {
+            Some(entry) => entry.2 = answer.to_string(),
+            None => self.entries.push((input.to_string(), part, answer.to_string())),
+        }
+    }
+
+    pub fn inputs(&self) -> Vec<&str> {
+        self.entries.iter()
+            .fold(Vec::new(), |mut inputs, (name, _, _)| {
+                if !inputs.contains(&name.as_str()) {
+                    inputs.push(name.as_str());
+                }
+
+                inputs
+            })
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "# input part answer")?;
+
+        for (input, part, answer) in self.entries.iter() {
+            writeln!(f, "{} {} {}", input, part, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut answers = Answers::parse("# input part answer\ninput.txt 1 11\n\ntest.txt 2 a,b c\n").unwrap();
+
+        assert_eq!(answers.get("input.txt", Part::One), Some("11"));
+        assert_eq!(answers.get("test.txt", Part::Two), Some("a,b c"));
+        assert_eq!(answers.get("input.txt", Part::Two), None);
+
+        answers.set("input.txt", Part::Two, "31");
+        answers.set("input.txt", Part::One, "12");
+
+        assert_eq!(answers.inputs(), vec!["input.txt", "test.txt"]);
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Answers::parse("input.txt 3 11\n").unwrap_err().to_string(), "1:11: invalid part '3'");
+        assert_eq!(Answers::parse("input.txt 1\n").unwrap_err().to_string(), "1:1: expected `<input> <part> <answer>` 'input.txt 1'");
+    }
+}
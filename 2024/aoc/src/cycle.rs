@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// every function here takes a deterministic `step` from one state to the next,
+// `None` means the process halted and therefore never loops
+
+
+/// A sequence of states enters its cycle after `start` steps and repeats every `length` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The index within the first pass of the cycle that step `n` lands on.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            return n;
+        }
+
+        self.start + (n - self.start) % self.length
+    }
+}
+
+/// Floyd's tortoise and hare, constant memory but steps every state about three times.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    let mut start = 0;
+
+    tortoise = initial;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+
+    hare = step(&tortoise)?;
+
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Brent's variant, constant memory and fewer steps than [`floyd`].
+pub fn brent<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    let mut start = 0;
+
+    tortoise = initial.clone();
+    hare = initial;
+
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Remembers every state it has seen, steps each state once at the cost of memory.
+pub fn hashed<S, F>(initial: S, step: F) -> Option<Cycle>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    history(initial, step, usize::MAX).1
+}
+
+/// The states from `initial` on, up to `limit` of them or until the first repeat, and the cycle if one was found.
+fn history<S, F>(initial: S, mut step: F, limit: usize) -> (Vec<S>, Option<Cycle>)
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+
+    while states.len() < limit {
+        let Some(next) = step(&states[states.len() - 1]) else {
+            break;
+        };
+
+        if let Some(start) = seen.get(&next) {
+            let cycle = Cycle { start: *start, length: states.len() - start };
+
+            return (states, Some(cycle));
+        }
+
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+
+    (states, None)
+}
+
+/// The state after `n` steps without taking them all once the sequence loops, `None` if it halts first.
+pub fn nth<S, F>(initial: S, step: F, n: usize) -> Option<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let (states, cycle) = history(initial, step, n.saturating_add(1));
+
+    match cycle {
+        Some(cycle) => states.get(cycle.reduce(n)).cloned(),
+        None => states.get(n).cloned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2 3 4 5 6 7 3 4 5 6 7 ...
+    fn looping(state: &u32) -> Option<u32> {
+        Some(if *state == 7 { 3 } else { state + 1 })
+    }
+
+    fn halting(state: &u32) -> Option<u32> {
+        (*state < 10).then_some(state + 1)
+    }
+
+    #[test]
+    fn detect() {
+        let cycle = Some(Cycle { start: 3, length: 5 });
+
+        assert_eq!(floyd(0, looping), cycle);
+        assert_eq!(brent(0, looping), cycle);
+        assert_eq!(hashed(0, looping), cycle);
+
+        assert_eq!(brent(7, looping), Some(Cycle { start: 0, length: 5 }));
+        assert_eq!(floyd(0, |state: &u32| Some(*state)), Some(Cycle { start: 0, length: 1 }));
+    }
+
+    #[test]
+    fn halts() {
+        assert_eq!(floyd(0, halting), None);
+        assert_eq!(brent(0, halting), None);
+        assert_eq!(hashed(0, halting), None);
+    }
+
+    #[test]
+    fn jump() {
+        assert_eq!(nth(0, looping, 2), Some(2));
+        assert_eq!(nth(0, looping, 8), Some(3));
+        assert_eq!(nth(0, looping, 1_000_000_000), Some(3 + (1_000_000_000 - 3) % 5));
+        assert_eq!(nth(0, halting, 10), Some(10));
+        assert_eq!(nth(0, halting, 11), None);
+    }
+}
@@ -0,0 +1,217 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::fmt;
+
+use crate::registry::Reference;
+use crate::random::Rng;
+use crate::{Answer, Day, Part};
+
+// a reference solver is the slow and obvious reading of the puzzle, inputs it rejects are skipped
+// because they say nothing about the solution
+
+type Answers = [Answer; 2];
+
+
+/// A generated input on which a solution and its reference disagree, shrunk as far as it would go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub expected: [Answer; 2],
+    pub actual: Result<[Answer; 2], String>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "seed {} size {}, shrunk to {} lines:", self.seed, self.size, self.input.lines().count())?;
+        write!(f, "{}", self.input)?;
+
+        for (index, part) in Part::ALL.iter().enumerate() {
+            write!(f, "part {}: expected {}", part, self.expected[index])?;
+
+            match &self.actual {
+                Ok(actual) if actual[index] == self.expected[index] => writeln!(f)?,
+                Ok(actual) => writeln!(f, ", got {}", actual[index])?,
+                Err(err) => writeln!(f, ", failed with {}", err)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Both answers of the solution of `day`, errors and panics alike end up as their message.
+pub fn solve(day: &Day, input: &str) -> Result<[Answer; 2], String> {
+    let run = || -> crate::Result<[Answer; 2]> {
+        let solution = (day.parse)(input)?;
+
+        Ok([Part::One.solve(solution.as_ref())?, Part::Two.solve(solution.as_ref())?])
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(answers) => answers.map_err(|err| err.to_string()),
+        Err(panic) => Err(panic.downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .map(|message| format!("panic: {}", message))
+            .unwrap_or_else(|| String::from("panic"))),
+    }
+}
+
+/// The reference answers and what the solution made of `input`, if they disagree.
+fn compare(day: &Day, reference: Reference, input: &str) -> Option<(Answers, Result<Answers, String>)> {
+    // a reference that overflows on some input it never expected rejects it like any other
+    let expected = panic::catch_unwind(|| reference(input)).ok()?.ok()?;
+    let actual = solve(day, input);
+
+    if actual.as_ref().is_ok_and(|actual| *actual == expected) {
+        return None;
+    }
+
+    Some((expected, actual))
+}
+
+/// Checks the solution of `day` against its reference on the input generated from `seed` and `size`.
+pub fn check(day: &Day, seed: u64, size: usize) -> crate::Result<Option<Mismatch>> {
+    let generate = day.generate.ok_or_else(|| format!("{} day {} has no input generator", day.year, day.day))?;
+    let reference = day.reference.ok_or_else(|| format!("{} day {} has no reference solution", day.year, day.day))?;
+
+    let input = generate(&mut Rng::new(seed), size);
+
+    if compare(day, reference, &input).is_none() {
+        return Ok(None);
+    }
+
+    let input = shrink(&input, |input| compare(day, reference, input).is_some());
+
+    Ok(compare(day, reference, &input).map(|(expected, actual)| Mismatch {
+        seed,
+        size,
+        input,
+        expected,
+        actual,
+    }))
+}
+
+/// Removes runs of `units` for as long as what is left still `fails`, halving the runs when nothing goes.
+fn reduce<F>(mut units: Vec<String>, fails: &mut F) -> Vec<String>
+where
+    F: FnMut(&[String]) -> bool,
+{
+    let mut chunk = (units.len() / 2).max(1);
+
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+
+            if !candidate.is_empty() && fails(&candidate) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if removed {
+            continue;
+        }
+
+        if chunk == 1 {
+            return units;
+        }
+
+        chunk /= 2;
+    }
+}
+
+/// A smaller input that still `fails`, first whole lines are dropped and then characters within the lines left.
+pub fn shrink<F>(input: &str, mut fails: F) -> String
+where
+    F: FnMut(&str) -> bool,
+{
+    let text = |lines: &[String]| lines.iter().map(|line| format!("{}\n", line)).collect::<String>();
+
+    let mut lines = reduce(input.lines().map(String::from).collect(), &mut |lines: &[String]| fails(&text(lines)));
+
+    for index in 0..lines.len() {
+        let characters = lines[index].chars().map(String::from).collect::<Vec<String>>();
+
+        let shrunk = reduce(characters, &mut |characters: &[String]| {
+            let mut candidate = lines.clone();
+
+            candidate[index] = characters.concat();
+
+            fails(&text(&candidate))
+        });
+
+        lines[index] = shrunk.concat();
+    }
+
+    text(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    // sums every number but gets it wrong as soon as a 7 shows up in a line of more than one number
+    struct Buggy(Vec<Vec<u64>>);
+
+    impl Solution for Buggy {
+        fn parse(input: &str) -> crate::Result<Buggy> {
+            Ok(Buggy(crate::parse::number_lines(input, "number")?))
+        }
+
+        fn part1(&self) -> crate::Result<Answer> {
+            Ok(self.0.iter().flatten().sum::<u64>().into())
+        }
+
+        fn part2(&self) -> crate::Result<Answer> {
+            Ok(self.0.iter().map(|line| line.iter().sum::<u64>() + (line.len() > 1 && line.contains(&7)) as u64).sum::<u64>().into())
+        }
+    }
+
+    fn reference(input: &str) -> crate::Result<[Answer; 2]> {
+        let sum = input.split_whitespace().map(|number| number.parse::<u64>()).sum::<Result<u64, _>>()?;
+
+        Ok([sum.into(), sum.into()])
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| format!("{} {} {}\n", rng.below(10), rng.below(10), rng.below(10))).collect()
+    }
+
+    const DAY: Day = Day::new::<Buggy>(2024, 1).generator(generate).reference(reference);
+
+    #[test]
+    fn shrinks() {
+        assert_eq!(shrink("1 2\n3 7 4\n5\n", |input| input.contains('7') && input.contains('3')), "37\n");
+        assert_eq!(shrink("abc\n", |input| input.contains('b')), "b\n");
+    }
+
+    #[test]
+    fn finds_mismatch() {
+        let mismatch = (0..20).find_map(|seed| check(&DAY, seed, 30).unwrap()).unwrap();
+
+        assert_eq!(mismatch.input.lines().count(), 1);
+        assert!(mismatch.input.contains('7'));
+        assert_eq!(mismatch.input.split_whitespace().count(), 2);
+        assert_eq!(mismatch.actual.as_ref().unwrap()[0], mismatch.expected[0]);
+        assert_ne!(mismatch.actual.as_ref().unwrap()[1], mismatch.expected[1]);
+    }
+
+    #[test]
+    fn agrees() {
+        let honest = Day::new::<Buggy>(2024, 1)
+            .generator(|rng, size| format!("{}\n", rng.below(size as u64 + 1)))
+            .reference(reference);
+
+        assert_eq!(check(&honest, 1, 10).unwrap(), None);
+        assert!(check(&Day::new::<Buggy>(2024, 1), 1, 10).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use std::str::FromStr;
+use std::error::Error;
+use std::fmt;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Locates `token` inside `input`, the token has to be a slice of the input,
+    /// anything else is reported at the end of the input.
+    pub fn at(input: &str, token: &str, message: &str) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset + token.len() <= input.len())
+            .unwrap_or(input.len());
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+
+        ParseError::new(line, column, token, message)
+    }
+
+    /// Reports a token that should have been there but the input ended first.
+    pub fn eof(input: &str, message: &str) -> ParseError {
+        ParseError::at(input, &input[input.len()..], &format!("{} at end of input", message))
+    }
+
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+
+        if !self.token.is_empty() {
+            write!(f, " '{}'", self.token.escape_debug())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a token that is a slice of `input`, so a failure can point at it.
+pub fn parse<T: FromStr>(input: &str, token: &str, what: &str) -> Result<T, ParseError> {
+    token.parse::<T>()
+        .map_err(|_| ParseError::at(input, token, &format!("invalid {}", what)))
+}
+
+/// Attaches the file an input came from to the error, parse errors keep their position.
+pub fn with_file(err: Box<dyn Error>, file: &str) -> Box<dyn Error> {
+    match err.downcast::<ParseError>() {
+        Ok(err) => Box::new(err.with_file(file)),
+        Err(err) => format!("{}: {}", file, err).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "1 2\n3 x4\n";
+        let token = &input[6..8];
+
+        let err = ParseError::at(input, token, "invalid integer");
+
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "x4"));
+        assert_eq!(err.with_file("input.txt").to_string(), "input.txt:2:3: invalid integer 'x4'");
+    }
+
+    #[test]
+    fn end_of_input() {
+        let err = ParseError::eof("1 2\n3", "missing value");
+
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.to_string(), "2:2: missing value at end of input");
+    }
+
+    #[test]
+    fn foreign_token() {
+        let err = ParseError::at("abc", "zzz", "unexpected");
+
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+
+    #[test]
+    fn parse_token() {
+        let input = "12 -3";
+
+        assert_eq!(parse::<i32>(input, &input[3..], "integer"), Ok(-3));
+        assert_eq!(parse::<usize>(input, &input[3..], "integer").unwrap_err().column, 4);
+    }
+
+    #[test]
+    fn attach_file() {
+        let err = with_file(Box::new(ParseError::new(1, 1, "x", "invalid")), "test.txt");
+
+        assert_eq!(err.to_string(), "test.txt:1:1: invalid 'x'");
+        assert_eq!(with_file("boom".into(), "test.txt").to_string(), "test.txt: boom");
+    }
+}
@@ -0,0 +1,244 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+
+/// A location on the plane, `y` grows downwards like the rows of the input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The difference between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point {
+            x,
+            y,
+        }
+    }
+
+    pub fn manhattan(&self, other: Point) -> i64 {
+        (*self - other).manhattan()
+    }
+
+    pub fn chebyshev(&self, other: Point) -> i64 {
+        (*self - other).chebyshev()
+    }
+
+    /// The column and row of this point in a `width` by `height` grid, `None` outside of it.
+    pub fn to_grid(&self, width: usize, height: usize) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.x).ok().filter(|x| *x < width)?;
+        let y = usize::try_from(self.y).ok().filter(|y| *y < height)?;
+
+        Some((x, y))
+    }
+
+    /// The point of a column and row, panics if they don't fit the coordinates.
+    pub fn from_grid(x: usize, y: usize) -> Point {
+        Point::new(i64::try_from(x).expect("column out of range"), i64::try_from(y).expect("row out of range"))
+    }
+
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.vector()
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Vector {
+        Vector {
+            x,
+            y,
+        }
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev(&self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        Point::new(self.x - vector.x, self.y - vector.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The four diagonal directions, clockwise from up and to the right.
+    pub const DIAGONAL: [Direction; 4] = [Direction::UpRight, Direction::DownRight, Direction::DownLeft, Direction::UpLeft];
+
+    /// All eight directions, clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    fn turn(&self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|direction| direction == self).unwrap_or(0);
+
+        Direction::ALL[(index + eighths) % Direction::ALL.len()]
+    }
+
+    /// Turns a quarter clockwise, diagonals stay diagonals.
+    pub fn rotate_cw(&self) -> Direction {
+        self.turn(2)
+    }
+
+    pub fn rotate_ccw(&self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn vector(&self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::UpRight => Vector::new(1, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::DownRight => Vector::new(1, 1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::DownLeft => Vector::new(-1, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+
+        assert_eq!(b - a, Vector::new(3, -4));
+        assert_eq!(a + (b - a) * 2, Point::new(7, -6));
+        assert_eq!(a - -(b - a), b);
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (7, 4));
+    }
+
+    #[test]
+    fn grid_coordinates() {
+        assert_eq!(Point::new(1, 2).to_grid(2, 3), Some((1, 2)));
+        assert_eq!(Point::new(2, 0).to_grid(2, 3), None);
+        assert_eq!(Point::new(-1, 0).to_grid(2, 3), None);
+        assert_eq!(Point::from_grid(1, 2), Point::new(1, 2));
+    }
+
+    #[test]
+    fn rotate() {
+        let mut direction = Direction::Up;
+
+        for expected in [Direction::Right, Direction::Down, Direction::Left, Direction::Up] {
+            direction = direction.rotate_cw();
+
+            assert_eq!(direction, expected);
+        }
+
+        assert_eq!(Direction::UpRight.rotate_ccw(), Direction::UpLeft);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+        assert!(Direction::ALL.iter().all(|direction| direction.opposite().vector() == -direction.vector()));
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(Point::new(0, 1).step(Direction::Up), Point::new(0, 0));
+        assert_eq!(Point::new(0, 1).step(Direction::DownLeft), Point::new(-1, 2));
+    }
+}
@@ -0,0 +1,257 @@
+use std::ops::{Index, IndexMut};
+use std::fmt;
+
+use crate::geometry::{Direction, Point, Vector};
+use crate::ParseError;
+
+
+/// A rectangular grid stored row by row in a single vector.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics unless there are exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "a {}x{} grid needs {} cells", width, height, width * height);
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid of characters, `f` maps every cell and gets it as a slice of `input` so errors point at it.
+    pub fn parse<'a, F>(input: &'a str, mut f: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(&'a str) -> Result<T, ParseError>,
+    {
+        let rows = crate::input::rows(input)?;
+        let width = rows[0].chars().count();
+
+        let cells = rows.iter()
+            .flat_map(|row| row.char_indices().map(move |(offset, c)| &row[offset..offset + c.len_utf8()]))
+            .map(&mut f)
+            .collect::<Result<Vec<T>, ParseError>>()?;
+
+        Ok(Grid::new(width, rows.len(), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        point.to_grid(self.width, self.height).map(|(x, y)| y * self.width + x)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.width * self.height).map(move |index| Point::from_grid(index % width, index / width))
+    }
+
+    /// Every cell together with its point in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The neighbour of `point` in `direction`, unless that leaves the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        Some(point.step(direction)).filter(|point| self.contains(*point))
+    }
+
+    /// Points from `point` onwards, moving by `vector` until the edge of the grid.
+    pub fn ray(&self, point: Point, vector: Vector) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(Some(point), move |point| Some(*point + vector)).take_while(|point| self.contains(*point))
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL.into_iter().filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter().filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals running down and to the right, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height).rev().map(|y| Point::from_grid(0, y)).chain((1..self.width).map(|x| Point::from_grid(x, 0)));
+
+        starts.map(|start| self.ray(start, Direction::DownRight.vector()).map(|point| &self[point]))
+    }
+
+    /// Diagonals running down and to the left, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let right = self.width.saturating_sub(1);
+        let starts = (0..self.width).map(|x| Point::from_grid(x, 0)).chain((1..self.height).map(move |y| Point::from_grid(right, y)));
+
+        starts.map(|start| self.ray(start, Direction::DownLeft.vector()).map(|point| &self[point]))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    pub fn find<F>(&self, mut f: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| f(cell)).map(|(point, _)| point)
+    }
+}
+
+impl Grid<char> {
+    pub fn chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(input, |cell| Ok(cell.chars().next().unwrap_or_default()))
+    }
+}
+
+/// Unchecked indexing, panics outside of the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(index) => &self.cells[index],
+            None => panic!("{:?} is outside of a {}x{} grid", point, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{:?} is outside of a {}x{} grid", point, self.width, self.height),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::chars("abc\ndef\n").unwrap()
+    }
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let input = "12\n3x\n";
+        let err = Grid::parse(input, |cell| crate::error::parse::<u32>(input, cell, "digit")).unwrap_err();
+
+        assert_eq!(err.to_string(), "2:2: invalid digit 'x'");
+    }
+
+    #[test]
+    fn indexing() {
+        let mut grid = grid();
+
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+
+        *grid.get_mut(Point::new(0, 1)).unwrap() = 'x';
+        grid[Point::new(1, 1)] = 'y';
+
+        assert_eq!(grid.row(1), ['x', 'y', 'f']);
+        assert_eq!(grid.find(|cell| *cell == 'y'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unchecked() {
+        let _ = grid()[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).collect::<Vec<Point>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).map(|point| grid[point]).collect::<String>(), "bcfda");
+    }
+
+    #[test]
+    fn lines() {
+        let grid = grid();
+
+        assert_eq!(grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<String>>(), vec!["abc", "def"]);
+        assert_eq!(grid.columns().map(collect).collect::<Vec<String>>(), vec!["ad", "be", "cf"]);
+        assert_eq!(grid.diagonals().map(collect).collect::<Vec<String>>(), vec!["d", "ae", "bf", "c"]);
+        assert_eq!(grid.anti_diagonals().map(collect).collect::<Vec<String>>(), vec!["a", "bd", "ce", "f"]);
+        assert_eq!(grid.ray(Point::new(0, 1), Direction::Right.vector()).map(|point| grid[point]).collect::<String>(), "def");
+    }
+}
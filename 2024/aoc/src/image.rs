@@ -0,0 +1,327 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::fs;
+
+use crate::animate::{Colour, Frame};
+use crate::Grid;
+
+// png needs a crc32 per chunk and zlib for the pixels, stored deflate blocks keep that to a few lines
+// at the cost of files about as large as a ppm
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const STORED: usize = 65535;
+
+const CRC: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut n = 0;
+
+    while n < 256 {
+        let mut crc = n as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 { 0xedb88320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+
+        table[n] = crc;
+        n += 1;
+    }
+
+    table
+};
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// The colour `t` of the way from `self` to `other`, `t` is clamped to 0..=1.
+    pub fn mix(&self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Rgb(channel(self.0, other.0), channel(self.1, other.1), channel(self.2, other.2))
+    }
+}
+
+impl From<Colour> for Rgb {
+    fn from(colour: Colour) -> Rgb {
+        match colour {
+            Colour::Default => Rgb(30, 30, 30),
+            Colour::Red => Rgb(220, 50, 47),
+            Colour::Green => Rgb(80, 200, 80),
+            Colour::Yellow => Rgb(240, 200, 40),
+            Colour::Blue => Rgb(60, 110, 230),
+            Colour::Magenta => Rgb(200, 70, 200),
+            Colour::Cyan => Rgb(40, 190, 200),
+            Colour::White => Rgb(235, 235, 235),
+            Colour::Grey => Rgb(90, 90, 90),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Png,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+
+    pub fn from_path(path: &Path) -> crate::Result<Format> {
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or_default();
+
+        extension.parse::<Format>().map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Format, String> {
+        match format.to_ascii_lowercase().as_str() {
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            _ => Err(format!("unknown image format '{}', expected ppm or png", format)),
+        }
+    }
+}
+
+/// An RGB picture, every grid cell becomes a `scale` by `scale` square.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new<T, F>(grid: &Grid<T>, scale: usize, mut palette: F) -> Image
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        let scale = scale.max(1);
+        let colours = grid.map(&mut palette);
+
+        let pixels = colours.rows()
+            .flat_map(|row| std::iter::repeat_n(row, scale))
+            .flat_map(|row| row.iter().flat_map(|colour| std::iter::repeat_n(*colour, scale)))
+            .collect::<Vec<Rgb>>();
+
+        Image {
+            pixels: Grid::new(grid.width() * scale, grid.height() * scale, pixels),
+        }
+    }
+
+    /// A frame of an animation with its colours, the glyphs and caption don't make it into the picture.
+    pub fn from_frame(frame: &Frame, scale: usize) -> Image {
+        Image::new(frame.cells(), scale, |cell| cell.colour.into())
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+
+        for pixel in self.pixels.rows().flatten() {
+            bytes.extend([pixel.0, pixel.1, pixel.2]);
+        }
+
+        bytes
+    }
+
+    pub fn png(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+
+        header.extend((self.width() as u32).to_be_bytes());
+        header.extend((self.height() as u32).to_be_bytes());
+        // 8 bits per channel, truecolour, no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        // every scanline starts with its filter type, 0 leaves the row as it is
+        let mut scanlines = Vec::with_capacity(self.height() * (self.width() * 3 + 1));
+
+        for row in self.pixels.rows() {
+            scanlines.push(0);
+            scanlines.extend(row.iter().flat_map(|pixel| [pixel.0, pixel.1, pixel.2]));
+        }
+
+        let mut bytes = SIGNATURE.to_vec();
+
+        chunk(&mut bytes, b"IHDR", &header);
+        chunk(&mut bytes, b"IDAT", &zlib(&scanlines));
+        chunk(&mut bytes, b"IEND", &[]);
+
+        bytes
+    }
+
+    pub fn encode(&self, format: Format) -> Vec<u8> {
+        match format {
+            Format::Ppm => self.ppm(),
+            Format::Png => self.png(),
+        }
+    }
+
+    /// Writes the image in the format its extension asks for.
+    pub fn save(&self, path: &Path) -> crate::Result<()> {
+        let format = Format::from_path(path)?;
+
+        fs::write(path, self.encode(format)).map_err(|err| format!("{}: {}", path.display(), err).into())
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc: u32, byte| CRC[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8))
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let blocks = data.chunks(STORED).collect::<Vec<&[u8]>>();
+
+    if blocks.is_empty() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    for (index, block) in blocks.iter().enumerate() {
+        let length = block.len() as u16;
+
+        bytes.push((index + 1 == blocks.len()) as u8);
+        bytes.extend(length.to_le_bytes());
+        bytes.extend((!length).to_le_bytes());
+        bytes.extend(*block);
+    }
+
+    bytes.extend(adler32(data).to_be_bytes());
+
+    bytes
+}
+
+fn chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+
+    let start = bytes.len();
+
+    bytes.extend(kind);
+    bytes.extend(data);
+
+    let crc = crc32(&bytes[start..]);
+
+    bytes.extend(crc.to_be_bytes());
+}
+
+/// Numbered images in a directory, `frame-00000.png`, `frame-00001.png` and so on.
+pub struct Sequence {
+    dir: PathBuf,
+    format: Format,
+    next: usize,
+}
+
+impl Sequence {
+    pub fn new(dir: &Path, format: Format) -> crate::Result<Sequence> {
+        fs::create_dir_all(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+
+        Ok(Sequence {
+            dir: dir.to_path_buf(),
+            format,
+            next: 0,
+        })
+    }
+
+    pub fn push(&mut self, image: &Image) -> crate::Result<PathBuf> {
+        let path = self.dir.join(format!("frame-{:05}.{}", self.next, self.format.extension()));
+
+        fs::write(&path, image.encode(self.format)).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        self.next += 1;
+
+        Ok(path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.next
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::chars("ab\n").unwrap();
+
+        Image::new(&grid, 2, |c| if *c == 'a' { Rgb::BLACK } else { Rgb(255, 0, 0) })
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn ppm() {
+        let image = image();
+
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(&image.ppm()[..11], b"P6\n4 2\n255\n");
+        assert_eq!(&image.ppm()[11..23], &[0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0]);
+    }
+
+    #[test]
+    fn png() {
+        let png = image().png();
+
+        assert_eq!(&png[..8], &SIGNATURE);
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 4, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 8..], &[b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]);
+    }
+
+    #[test]
+    fn stored_blocks() {
+        let data = vec![7; STORED + 10];
+        let stream = zlib(&data);
+
+        assert_eq!(stream.len(), 2 + 5 + STORED + 5 + 10 + 4);
+        assert_eq!(stream[2], 0);
+        assert_eq!(stream[2 + 5 + STORED], 1);
+        assert_eq!(zlib(&[]), vec![0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Format::from_path(Path::new("out/day6.PNG")).unwrap(), Format::Png);
+        assert!(Format::from_path(Path::new("day6.gif")).is_err());
+        assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::{self, Read};
+use std::fs;
+
+use crate::ParseError;
+
+
+pub fn read(file: &str) -> crate::Result<String> {
+    fs::read_to_string(file)
+        .map_err(|err| format!("failed to read {}: {}", file, err).into())
+}
+
+pub fn stdin() -> crate::Result<String> {
+    let mut content = String::new();
+
+    io::stdin().read_to_string(&mut content)
+        .map_err(|err| format!("failed to read stdin: {}", err))?;
+
+    Ok(content)
+}
+
+/// Splits a grid into its rows, every row has to be as wide as the first one.
+pub fn rows(input: &str) -> Result<Vec<&str>, ParseError> {
+    let rows = input.lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect::<Vec<&str>>();
+
+    let rows = &rows[..rows.iter().rposition(|row| !row.is_empty()).map(|last| last + 1).unwrap_or(0)];
+
+    let width = rows.first()
+        .map(|row| row.chars().count())
+        .ok_or_else(|| ParseError::eof(input, "expected at least one row"))?;
+
+    match rows.iter().find(|row| row.chars().count() != width) {
+        Some(row) => Err(ParseError::at(input, row, &format!("expected a row of width {}", width))),
+        None => Ok(rows.to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_rows() {
+        assert_eq!(rows("ab\ncd\n\n").unwrap(), vec!["ab", "cd"]);
+        assert_eq!(rows("ab\r\ncd\r\n").unwrap(), vec!["ab", "cd"]);
+        assert_eq!(rows("ab\nc\nde").unwrap_err().to_string(), "2:1: expected a row of width 2 'c'");
+        assert!(rows("\n\n").is_err());
+    }
+}
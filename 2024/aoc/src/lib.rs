@@ -0,0 +1,24 @@
+pub mod animate;
+pub mod answers;
+pub mod cycle;
+pub mod differential;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod log;
+pub mod parse;
+pub mod random;
+pub mod registry;
+pub mod search;
+pub mod solution;
+pub mod time;
+
+pub use error::ParseError;
+pub use geometry::{Direction, Point, Vector};
+pub use grid::Grid;
+pub use registry::Day;
+pub use solution::{Answer, Part, Solution};
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
@@ -0,0 +1,190 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+use std::str::FromStr;
+use std::fmt;
+
+pub const ENV: &str = "AOC_LOG";
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new());
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(level: &str) -> Result<Level, String> {
+        match level.to_ascii_lowercase().as_str() {
+            "off" => Ok(Level::Off),
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("invalid log level: {}", level)),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Off => "OFF",
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// A filter like `warn,day6=trace`, the bare level applies to every target without its own entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    default: Level,
+    targets: Vec<(String, Level)>,
+}
+
+impl Filter {
+    pub const fn new() -> Filter {
+        Filter {
+            default: Level::Off,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn level(&self, target: &str) -> Level {
+        let target = target.split("::").next().unwrap_or(target);
+
+        self.targets.iter()
+            .find(|(name, _)| name == target)
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    fn max(&self) -> Level {
+        self.targets.iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Filter {
+        Filter::new()
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Filter, String> {
+        spec.split(',')
+            .map(|directive| directive.trim())
+            .filter(|directive| !directive.is_empty())
+            .try_fold(Filter::new(), |mut filter, directive| {
+                match directive.split_once('=') {
+                    Some((target, level)) => filter.targets.push((target.to_string(), level.parse::<Level>()?)),
+                    None => filter.default = directive.parse::<Level>()?,
+                }
+
+                Ok(filter)
+            })
+    }
+}
+
+pub fn set(filter: Filter) {
+    MAX_LEVEL.store(filter.max() as u8, Ordering::Relaxed);
+
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = filter;
+}
+
+/// Configures logging from `spec`, or from the environment when no spec is given.
+pub fn init(spec: Option<&str>) -> crate::Result<()> {
+    let spec = spec.map(String::from).or_else(|| std::env::var(ENV).ok());
+
+    if let Some(spec) = spec {
+        set(spec.parse::<Filter>()?);
+    }
+
+    Ok(())
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && level <= FILTER.read().unwrap_or_else(|err| err.into_inner()).level(target)
+}
+
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_filter() {
+        let filter = "warn, day6=trace,day9=debug".parse::<Filter>().unwrap();
+
+        assert_eq!(filter.level("day6"), Level::Trace);
+        assert_eq!(filter.level("day9::partition"), Level::Debug);
+        assert_eq!(filter.level("day1"), Level::Warn);
+        assert_eq!(filter.max(), Level::Trace);
+        assert!("day6=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn silent_by_default() {
+        let filter = Filter::default();
+
+        assert_eq!(filter.level("day1"), Level::Off);
+        assert!(!enabled(Level::Error, "day1"));
+    }
+}
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use crate::{Grid, ParseError};
+
+// every helper hands out slices of `input` or parses them in place, so errors can always point at the token
+
+
+/// The lines that aren't blank, without their line endings.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.trim().is_empty())
+}
+
+/// Blocks of lines separated by blank lines, like the two halves of day 5.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+
+    for line in input.split_inclusive('\n') {
+        let offset = line.as_ptr() as usize - input.as_ptr() as usize;
+
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(input[start..end].trim_end());
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.len();
+        }
+    }
+
+    if let Some(start) = start {
+        sections.push(input[start..end].trim_end());
+    }
+
+    sections
+}
+
+/// Whitespace separated numbers in `text`, which has to be a slice of `input`.
+pub fn numbers<T: FromStr>(input: &str, text: &str, what: &str) -> Result<Vec<T>, ParseError> {
+    text.split_ascii_whitespace()
+        .map(|token| crate::error::parse::<T>(input, token, what))
+        .collect()
+}
+
+/// Numbers in `text` separated by `separator`, like `75,47,61`.
+pub fn separated<T: FromStr>(input: &str, text: &str, separator: char, what: &str) -> Result<Vec<T>, ParseError> {
+    text.split(separator)
+        .map(|token| crate::error::parse::<T>(input, token.trim(), what))
+        .collect()
+}
+
+/// A list of numbers for every line that isn't blank.
+pub fn number_lines<T: FromStr>(input: &str, what: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    lines(input)
+        .map(|line| numbers(input, line, what))
+        .collect()
+}
+
+/// Lines of exactly `count` numbers turned into `count` columns.
+pub fn columns<T: FromStr>(input: &str, count: usize, what: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut columns = (0..count).map(|_| Vec::new()).collect::<Vec<Vec<T>>>();
+
+    for line in lines(input) {
+        let values = numbers::<T>(input, line, what)?;
+
+        if values.len() != count {
+            return Err(ParseError::at(input, line, &format!("expected {} {}s per line, found {}", count, what, values.len())));
+        }
+
+        for (column, value) in columns.iter_mut().zip(values) {
+            column.push(value);
+        }
+    }
+
+    Ok(columns)
+}
+
+fn digit(input: &str, cell: &str, what: &str) -> Result<u8, ParseError> {
+    cell.chars()
+        .next()
+        .and_then(|c| c.to_digit(10))
+        .map(|digit| digit as u8)
+        .ok_or_else(|| ParseError::at(input, cell, &format!("expected a {}", what)))
+}
+
+/// A single line of digits like `2333133121414131402`.
+pub fn digits(input: &str, what: &str) -> Result<Vec<u8>, ParseError> {
+    let line = input.trim_end();
+
+    line.char_indices()
+        .map(|(offset, c)| digit(input, &line[offset..offset + c.len_utf8()], what))
+        .collect()
+}
+
+/// A grid with a digit in every cell.
+pub fn digit_grid<T: From<u8>>(input: &str, what: &str) -> Result<Grid<T>, ParseError> {
+    Grid::parse(input, |cell| digit(input, cell, what).map(T::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks() {
+        assert_eq!(lines("a\r\n\n  \nb\n").collect::<Vec<&str>>(), vec!["a", "b"]);
+        assert_eq!(sections("a\nb\n\n\nc\r\n\r\nd"), vec!["a\nb", "c", "d"]);
+        assert_eq!(sections("\n\n"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn lists() {
+        let input = "3   4\n4   3\n\n2 5\n";
+
+        assert_eq!(columns::<u32>(input, 2, "id").unwrap(), vec![vec![3, 4, 2], vec![4, 3, 5]]);
+        assert_eq!(number_lines::<i32>("1 -2\n3\n", "level").unwrap(), vec![vec![1, -2], vec![3]]);
+        assert_eq!(separated::<u32>("75,47,61", "75,47,61", ',', "page").unwrap(), vec![75, 47, 61]);
+
+        assert_eq!(columns::<u32>("1 2\n3\n", 2, "id").unwrap_err().to_string(), "2:1: expected 2 ids per line, found 1 '3'");
+        assert_eq!(number_lines::<i32>("1 2\n3 x\n", "level").unwrap_err().to_string(), "2:3: invalid level 'x'");
+    }
+
+    #[test]
+    fn digit_strings() {
+        assert_eq!(digits("2333\n", "digit").unwrap(), vec![2, 3, 3, 3]);
+        assert_eq!(digits("23 3\n", "digit").unwrap_err().to_string(), "1:3: expected a digit ' '");
+
+        let grid = digit_grid::<i32>("01\n23\n", "height").unwrap();
+
+        assert_eq!(grid.to_string(), "01\n23\n");
+        assert_eq!(digit_grid::<u8>("01\n2.\n", "height").unwrap_err().to_string(), "2:2: expected a height '.'");
+    }
+}
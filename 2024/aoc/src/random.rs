@@ -0,0 +1,108 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// xorshift64* is plenty for generating inputs and keeps every seed reproducible across platforms
+
+
+/// A seeded pseudo random generator, the same seed always produces the same numbers.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // splitmix64 spreads small seeds over the whole state and keeps it away from zero
+        let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        Rng {
+            state: (z ^ (z >> 31)).max(1),
+        }
+    }
+
+    /// A seed from the clock, for when any seed will do as long as it can be printed and reused.
+    pub fn seed() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos() as u64)
+            .unwrap_or_default()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+
+        self.state.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    /// A number in `0..bound`, panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low <= high, "empty range {}..={}", low, high);
+
+        match (high - low).checked_add(1) {
+            Some(bound) => low + self.below(bound),
+            None => self.next_u64(),
+        }
+    }
+
+    /// True with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// A random element, panics if `items` is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert_ne!(numbers(0), vec![0; 5]);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let values = (0..1000).map(|_| rng.between(3, 6)).collect::<Vec<u64>>();
+
+        assert!(values.iter().all(|value| (3..=6).contains(value)));
+        assert!((3..=6).all(|value| values.contains(&value)));
+        assert!(rng.between(0, u64::MAX) > 0);
+
+        let mut items = (0..10).collect::<Vec<u32>>();
+
+        rng.shuffle(&mut items);
+        items.sort();
+
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+    }
+}
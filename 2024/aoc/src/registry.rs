@@ -0,0 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use crate::random::Rng;
+use crate::{Answer, Solution};
+
+
+/// Writes a random input of roughly `size` lines, cells or numbers, whatever the day is made of.
+pub type Generate = fn(&mut Rng, usize) -> String;
+
+/// A slow but obviously correct solver for both parts that the solution is checked against.
+pub type Reference = fn(&str) -> crate::Result<[Answer; 2]>;
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub parse: fn(&str) -> crate::Result<Box<dyn Solution>>,
+    pub generate: Option<Generate>,
+    pub reference: Option<Reference>,
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> crate::Result<Box<dyn Solution>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(year: u32, day: u32) -> Day {
+        Day {
+            year,
+            day,
+            parse: parse::<S>,
+            generate: None,
+            reference: None,
+        }
+    }
+
+    pub const fn generator(self, generate: Generate) -> Day {
+        Day {
+            generate: Some(generate),
+            ..self
+        }
+    }
+
+    pub const fn reference(self, reference: Reference) -> Day {
+        Day {
+            reference: Some(reference),
+            ..self
+        }
+    }
+
+    pub fn dir(&self, root: &Path) -> PathBuf {
+        dir(root, self.year, self.day)
+    }
+}
+
+/// Directory of a day, whether or not it has a solution registered.
+pub fn dir(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{}", day))
+}
+
+pub fn select(days: &[Day], year: Option<u32>, day: Option<u32>) -> crate::Result<Vec<&Day>> {
+    let selected = days.iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year) && day.is_none_or(|day| entry.day == day))
+        .collect::<Vec<&Day>>();
+
+    if selected.is_empty() {
+        return Err(match (year, day) {
+            (Some(year), Some(day)) => format!("no solution registered for {} day {}", year, day),
+            (Some(year), None) => format!("no solutions registered for {}", year),
+            _ => String::from("no solutions registered"),
+        }.into());
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo(String);
+
+    impl Solution for Echo {
+        fn parse(input: &str) -> crate::Result<Echo> {
+            Ok(Echo(input.to_string()))
+        }
+
+        fn part1(&self) -> crate::Result<Answer> {
+            Ok(self.0.as_str().into())
+        }
+
+        fn part2(&self) -> crate::Result<Answer> {
+            Ok(self.0.len().into())
+        }
+    }
+
+    const DAYS: &[Day] = &[Day::new::<Echo>(2023, 1), Day::new::<Echo>(2024, 1), Day::new::<Echo>(2024, 2)];
+
+    #[test]
+    fn select_days() {
+        assert_eq!(select(DAYS, None, None).unwrap().len(), 3);
+        assert_eq!(select(DAYS, Some(2024), None).unwrap().len(), 2);
+        assert_eq!(select(DAYS, Some(2024), Some(2)).unwrap()[0].day, 2);
+        assert!(select(DAYS, Some(2024), Some(3)).is_err());
+    }
+
+    #[test]
+    fn parse_erased() {
+        let solution = (DAYS[0].parse)("abc").unwrap();
+
+        assert_eq!(solution.part1().unwrap().to_string(), "abc");
+        assert_eq!(solution.part2().unwrap().to_string(), "3");
+        assert_eq!(DAYS[2].dir(Path::new("/aoc")), PathBuf::from("/aoc/2024/day2"));
+    }
+}
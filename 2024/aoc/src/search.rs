@@ -0,0 +1,213 @@
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::hash::Hash;
+
+
+/// Distance in steps from `start` to every node it can reach.
+pub fn bfs<N, F, I>(start: N, mut neighbours: F) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Nodes reachable from `start` in depth first order, `start` included.
+pub fn dfs<N, F, I>(start: N, mut neighbours: F) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if seen.insert(node.clone()) {
+            stack.extend(neighbours(&node).into_iter().filter(|next| !seen.contains(next)));
+            order.push(node);
+        }
+    }
+
+    order
+}
+
+/// Every node reachable from `start`, `start` included.
+pub fn reachable<N, F, I>(start: N, neighbours: F) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    dfs(start, neighbours).into_iter().collect()
+}
+
+/// The cheapest path to a node that satisfies `goal`, as its cost and every node along it.
+pub fn dijkstra<N, F, I, G>(start: N, neighbours: F, goal: G) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbours, |_| 0, goal)
+}
+
+/// Like [`dijkstra`], guided by a `heuristic` that must never overestimate the remaining cost.
+pub fn astar<N, F, I, H, G>(start: N, mut neighbours: F, mut heuristic: H, mut goal: G) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+{
+    // nodes live in `nodes` so the heap only has to order numbers
+    let mut nodes = vec![start.clone()];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+
+        if costs.get(&node).is_some_and(|best| *best < cost) {
+            continue;
+        }
+
+        if goal(&node) {
+            let mut path = vec![node];
+            let mut parent = parents[index];
+
+            while let Some(index) = parent {
+                path.push(nodes[index].clone());
+                parent = parents[index];
+            }
+
+            path.reverse();
+
+            return Some((cost, path));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+
+            if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                costs.insert(next.clone(), next_cost);
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, nodes.len())));
+                nodes.push(next);
+                parents.push(Some(index));
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of distinct paths from `start` to nodes that satisfy `goal`, the graph has to be acyclic.
+///
+/// Paths stop at the first goal they reach.
+pub fn count_paths<N, F, I, G>(start: N, mut neighbours: F, mut goal: G) -> u64
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    fn count<N, F, I, G>(node: &N, neighbours: &mut F, goal: &mut G, memo: &mut HashMap<N, u64>) -> u64
+    where
+        N: Clone + Eq + Hash,
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+        G: FnMut(&N) -> bool,
+    {
+        if let Some(paths) = memo.get(node) {
+            return *paths;
+        }
+
+        let paths = if goal(node) {
+            1
+        } else {
+            neighbours(node).into_iter()
+                .collect::<Vec<N>>()
+                .iter()
+                .map(|next| count(next, neighbours, goal, memo))
+                .sum()
+        };
+
+        memo.insert(node.clone(), paths);
+
+        paths
+    }
+
+    count(&start, &mut neighbours, &mut goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3, 0 -> 2 -> 3, 3 -> 4 and 5 on its own
+    fn edges(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1, 2],
+            1 | 2 => vec![3],
+            3 => vec![4],
+            _ => vec![],
+        }
+    }
+
+    fn weighted(node: &u32) -> Vec<(u32, u64)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(2, 1), (3, 10)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first() {
+        let distances = bfs(0, edges);
+
+        assert_eq!(distances.len(), 5);
+        assert_eq!((distances[&3], distances[&4]), (2, 3));
+    }
+
+    #[test]
+    fn depth_first() {
+        assert_eq!(dfs(0, edges), vec![0, 2, 3, 4, 1]);
+        assert_eq!(reachable(1, edges), HashSet::from([1, 3, 4]));
+        assert_eq!(reachable(5, edges), HashSet::from([5]));
+    }
+
+    #[test]
+    fn cheapest() {
+        assert_eq!(dijkstra(0, weighted, |node| *node == 3), Some((3, vec![0, 1, 2, 3])));
+        assert_eq!(astar(0, weighted, |node| 3 - (*node as u64).min(3), |node| *node == 3), Some((3, vec![0, 1, 2, 3])));
+        assert_eq!(dijkstra(3, weighted, |node| *node == 0), None);
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(count_paths(0, edges, |node| *node == 4), 2);
+        assert_eq!(count_paths(0, edges, |node| *node == 3), 2);
+        assert_eq!(count_paths(4, edges, |node| *node == 0), 0);
+    }
+}
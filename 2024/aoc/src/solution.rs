@@ -0,0 +1,135 @@
+use std::str::FromStr;
+use std::fmt;
+
+use crate::animate::Animation;
+use crate::image::Image;
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Answer(String);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Answer {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(usize, u32, u64, i32, i64, String, &str);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn solve(&self, solution: &dyn Solution) -> crate::Result<Answer> {
+        match self {
+            Part::One => solution.part1(),
+            Part::Two => solution.part2(),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(part: &str) -> Result<Part, String> {
+        match part {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part: {}", part)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+pub trait Solution {
+    fn parse(input: &str) -> crate::Result<Self>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> crate::Result<Answer>;
+
+    fn part2(&self) -> crate::Result<Answer>;
+
+    /// A step by step view of the solution for `aoc animate`, most days don't have one.
+    fn animation(&self) -> Option<Box<dyn Animation + '_>> {
+        None
+    }
+
+    /// A picture of the solved puzzle for `aoc export`.
+    fn image(&self) -> Option<Image> {
+        None
+    }
+
+    /// How `part` came to its answer, a line of text per step, for `aoc run --explain`.
+    fn explain(&self, _part: Part) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum {
+        numbers: Vec<usize>,
+    }
+
+    impl Solution for Sum {
+        fn parse(input: &str) -> crate::Result<Sum> {
+            Ok(Sum {
+                numbers: input.split_whitespace().map(|number| number.parse::<usize>()).collect::<Result<Vec<usize>, _>>()?,
+            })
+        }
+
+        fn part1(&self) -> crate::Result<Answer> {
+            Ok(self.numbers.iter().sum::<usize>().into())
+        }
+
+        fn part2(&self) -> crate::Result<Answer> {
+            Ok(self.numbers.iter().product::<usize>().into())
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let sum = Sum::parse("2 3 4").unwrap();
+
+        assert_eq!(sum.part1().unwrap().to_string(), "9");
+        assert_eq!(sum.part2().unwrap(), Answer::from(24usize));
+        assert!(Sum::parse("2 x").is_err());
+    }
+
+    #[test]
+    fn part() {
+        let sum = Sum::parse("2 3 4").unwrap();
+
+        assert_eq!("2".parse::<Part>(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(Part::One.solve(&sum).unwrap().to_string(), "9");
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+
+/// Formats a duration with a unit that keeps it readable, from nanoseconds up to seconds.
+pub fn format(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..1_000 => format!("{}ns", nanos),
+        1_000..1_000_000 => format!("{:.2}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Summary of repeated measurements of the same thing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+
+        let middle = samples.len() / 2;
+
+        let median = match samples.len() % 2 {
+            0 => (*samples.get(middle.checked_sub(1)?)? + samples[middle]) / 2,
+            _ => samples[middle],
+        };
+
+        Some(Stats {
+            iterations: samples.len(),
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(millis(&[5, 1, 3])).unwrap();
+
+        assert_eq!((stats.iterations, stats.min, stats.median, stats.max), (3, Duration::from_millis(1), Duration::from_millis(3), Duration::from_millis(5)));
+        assert_eq!(Stats::new(millis(&[4, 1, 2, 8])).unwrap().median, Duration::from_millis(3));
+        assert_eq!(Stats::new(Vec::new()), None);
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(format(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format(Duration::from_nanos(1_500)), "1.50µs");
+        assert_eq!(format(Duration::from_micros(2_345)), "2.35ms");
+        assert_eq!(format(Duration::from_millis(12_340)), "12.34s");
+    }
+}
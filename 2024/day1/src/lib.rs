@@ -0,0 +1,476 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use aoc::{Answer, ParseError, Solution};
+use aoc::random::Rng;
+
+
+/// Location lists side by side, one column per list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lists {
+    columns: Vec<Vec<usize>>,
+    ragged: Vec<ParseError>,
+}
+
+impl Lists {
+    /// The most common number of ids on a line decides how many lists there are, ties go to the wider one.
+    /// A line that is short of ids fills the lists from the left and ends up in `ragged`, unless `strict`
+    /// is set and it is rejected instead.
+    pub fn parse(input: &str, strict: bool) -> aoc::Result<Lists> {
+        let widths = aoc::parse::lines(input).fold(HashMap::new(), |mut widths, line| {
+            *widths.entry(line.split_ascii_whitespace().count()).or_insert(0) += 1;
+            widths
+        });
+
+        let width = widths.into_iter()
+            .max_by_key(|(width, lines)| (*lines, *width))
+            .map(|(width, _)| width)
+            .unwrap_or_default();
+
+        let mut columns = vec![Vec::new(); width];
+        let mut ragged = Vec::new();
+
+        for line in aoc::parse::lines(input) {
+            let ids = aoc::parse::numbers::<usize>(input, line, "location id")?;
+
+            if ids.len() != width {
+                let err = ParseError::at(input, line, &format!("expected {} location ids per line, found {}", width, ids.len()));
+
+                // there is no telling which list an extra id belongs to
+                if strict || ids.len() > width {
+                    return Err(err.into());
+                }
+
+                ragged.push(err);
+            }
+
+            for (column, id) in columns.iter_mut().zip(ids) {
+                column.push(id);
+            }
+        }
+
+        Ok(Lists {
+            columns,
+            ragged,
+        })
+    }
+
+    pub fn columns(&self) -> &[Vec<usize>] {
+        &self.columns
+    }
+
+    /// The number of lists.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Where ids were missing, with the line of every short line.
+    pub fn ragged(&self) -> &[ParseError] {
+        &self.ragged
+    }
+
+    /// Two of the lists to compare with each other.
+    pub fn pair(&self, left: usize, right: usize) -> Option<Part1> {
+        Some(Part1 {
+            left: self.columns.get(left)?.clone(),
+            right: self.columns.get(right)?.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Part1 {
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+impl Part1 {
+    pub fn sort(&mut self) {
+        self.left.sort();
+
+        self.right.sort();
+    }
+
+    /// Lists of different lengths only count the pairs the shorter one has.
+    pub fn total(&self) -> usize {
+        self.left.iter()
+            .zip(self.right.iter())
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum()
+    }
+
+    /// The distances between the sorted lists and the `top` shared ids, `None` without a single pair.
+    pub fn report(&self, top: usize) -> Option<Report> {
+        let mut sorted = self.clone();
+
+        sorted.sort();
+
+        let mut distances = sorted.left.iter()
+            .zip(sorted.right.iter())
+            .map(|(left, right)| left.abs_diff(*right))
+            .collect::<Vec<usize>>();
+
+        distances.sort_unstable();
+
+        let pairs = distances.len();
+
+        if pairs == 0 {
+            return None;
+        }
+
+        let middle = pairs / 2;
+
+        let median = if pairs % 2 == 0 {
+            (distances[middle - 1] + distances[middle]) as f64 / 2.0
+        } else {
+            distances[middle] as f64
+        };
+
+        // nearest rank, so every percentile is a distance that actually occurred
+        let percentiles = Report::PERCENTILES.iter()
+            .map(|p| (*p, distances[(*p as usize * pairs).div_ceil(100).max(1) - 1]))
+            .collect();
+
+        let histogram = distances.iter().fold(BTreeMap::new(), |mut histogram, distance| {
+            *histogram.entry(*distance).or_insert(0) += 1;
+            histogram
+        });
+
+        let ids = Histogram::new(&self.left, &self.right);
+
+        let mut shared = ids.shared()
+            .into_iter()
+            .map(|id| Shared { id, left: ids.left(id), right: ids.right(id) })
+            .collect::<Vec<Shared>>();
+
+        shared.sort_by_key(|shared| std::cmp::Reverse(shared.left + shared.right));
+        shared.truncate(top);
+
+        Some(Report {
+            pairs,
+            min: distances[0],
+            max: distances[pairs - 1],
+            mean: distances.iter().sum::<usize>() as f64 / pairs as f64,
+            median,
+            percentiles,
+            histogram: histogram.into_iter().collect(),
+            shared,
+        })
+    }
+}
+
+/// An id that is on both lists, with how often it shows up on either side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shared {
+    pub id: usize,
+    pub left: usize,
+    pub right: usize,
+}
+
+/// The spread of the distances between the sorted lists, and the shared ids that show up the most.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub pairs: usize,
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Every one of `PERCENTILES` with its distance.
+    pub percentiles: Vec<(u8, usize)>,
+    /// How many pairs are each distance apart, by ascending distance.
+    pub histogram: Vec<(usize, usize)>,
+    /// Most occurrences first, ties by ascending id.
+    pub shared: Vec<Shared>,
+}
+
+impl Report {
+    pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+    const BAR: usize = 40;
+
+    pub fn json(&self) -> String {
+        let percentiles = self.percentiles.iter()
+            .map(|(p, distance)| format!("\"p{}\":{}", p, distance))
+            .collect::<Vec<String>>();
+
+        let histogram = self.histogram.iter()
+            .map(|(distance, count)| format!("{{\"distance\":{},\"count\":{}}}", distance, count))
+            .collect::<Vec<String>>();
+
+        let shared = self.shared.iter()
+            .map(|shared| format!("{{\"id\":{},\"left\":{},\"right\":{}}}", shared.id, shared.left, shared.right))
+            .collect::<Vec<String>>();
+
+        format!(
+            "{{\"pairs\":{},\"min\":{},\"max\":{},\"mean\":{},\"median\":{},\"percentiles\":{{{}}},\"histogram\":[{}],\"shared\":[{}]}}",
+            self.pairs,
+            self.min,
+            self.max,
+            self.mean,
+            self.median,
+            percentiles.join(","),
+            histogram.join(","),
+            shared.join(","),
+        )
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} pairs, distance min {} max {} mean {:.2} median {}", self.pairs, self.min, self.max, self.mean, self.median)?;
+
+        let percentiles = self.percentiles.iter()
+            .map(|(p, distance)| format!("p{} {}", p, distance))
+            .collect::<Vec<String>>();
+
+        writeln!(f, "{}", percentiles.join(", "))?;
+
+        let most = self.histogram.iter().map(|(_, count)| *count).max().unwrap_or_default();
+        let width = self.histogram.last().map(|(distance, _)| distance.to_string().len()).unwrap_or_default();
+
+        writeln!(f, "distances:")?;
+
+        for (distance, count) in &self.histogram {
+            writeln!(f, "  {:>width$} {:>6} {}", distance, count, "#".repeat((count * Report::BAR).div_ceil(most)))?;
+        }
+
+        writeln!(f, "shared ids:")?;
+
+        for shared in &self.shared {
+            writeln!(f, "  {} {}x left {}x right", shared.id, shared.left, shared.right)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// How often every location id shows up in each of the two lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Histogram {
+    left: HashMap<usize, usize>,
+    right: HashMap<usize, usize>,
+}
+
+impl Histogram {
+    pub fn new(left: &[usize], right: &[usize]) -> Histogram {
+        let count = |ids: &[usize]| {
+            ids.iter().fold(HashMap::new(), |mut counts, id| {
+                *counts.entry(*id).or_insert(0) += 1;
+                counts
+            })
+        };
+
+        Histogram {
+            left: count(left),
+            right: count(right),
+        }
+    }
+
+    pub fn left(&self, id: usize) -> usize {
+        self.left.get(&id).copied().unwrap_or_default()
+    }
+
+    pub fn right(&self, id: usize) -> usize {
+        self.right.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Ids in both lists, in ascending order.
+    pub fn shared(&self) -> Vec<usize> {
+        sorted(self.left.keys().filter(|id| self.right.contains_key(id)))
+    }
+
+    /// Ids that never show up on the right, in ascending order.
+    pub fn only_left(&self) -> Vec<usize> {
+        sorted(self.left.keys().filter(|id| !self.right.contains_key(id)))
+    }
+
+    /// Ids that never show up on the left, in ascending order.
+    pub fn only_right(&self) -> Vec<usize> {
+        sorted(self.right.keys().filter(|id| !self.left.contains_key(id)))
+    }
+}
+
+fn sorted<'a>(ids: impl Iterator<Item = &'a usize>) -> Vec<usize> {
+    let mut ids = ids.copied().collect::<Vec<usize>>();
+
+    ids.sort_unstable();
+    ids
+}
+
+#[derive(Debug)]
+pub struct Part2 {
+    histogram: Histogram,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            histogram: Histogram::new(&part1.left, &part1.right),
+        }
+    }
+}
+
+impl Part2 {
+    pub fn histogram(&self) -> &Histogram {
+        &self.histogram
+    }
+
+    pub fn appearences(&self, value: &usize) -> usize {
+        self.histogram.right(*value)
+    }
+
+    /// Every distinct left id once, weighted by how often it shows up on the left.
+    pub fn similarity(&self) -> usize {
+        self.histogram.left.iter()
+            .map(|(id, count)| id * count * self.appearences(id))
+            .sum()
+    }
+}
+
+/// `size` pairs of five digit location ids, about half of the right ids also appear on the left.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let ids = (0..size.max(1)).map(|_| rng.between(10000, 99999)).collect::<Vec<u64>>();
+
+    (0..size.max(1))
+        .map(|_| {
+            let left = *rng.pick(&ids);
+            let right = if rng.chance(0.5) { *rng.pick(&ids) } else { rng.between(10000, 99999) };
+
+            format!("{}   {}\n", left, right)
+        })
+        .collect()
+}
+
+/// Both lists sorted, and every left id counted against the whole right list.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            [a, b] => {
+                left.push(a.parse::<usize>()?);
+                right.push(b.parse::<usize>()?);
+            },
+            _ => return Err(format!("expected two ids in '{}'", line).into()),
+        }
+    }
+
+    left.sort();
+    right.sort();
+
+    let distance = left.iter().zip(right.iter()).map(|(a, b)| a.abs_diff(*b)).sum::<usize>();
+    let similarity = left.iter().map(|a| a * right.iter().filter(|b| *b == a).count()).sum::<usize>();
+
+    Ok([distance.into(), similarity.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let lists = Lists::parse(input, true)?;
+
+        if lists.len() != 2 {
+            return Err(format!("expected a left and a right list, found {} lists", lists.len()).into());
+        }
+
+        Ok(Part1 {
+            left: lists.columns[0].clone(),
+            right: lists.columns[1].clone(),
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        let mut part1 = self.clone();
+
+        part1.sort();
+
+        Ok(part1.total().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).similarity().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = Part1::parse("3   4\n4   x3\n").err().unwrap().to_string();
+
+        assert_eq!(err, "2:5: invalid location id 'x3'");
+    }
+
+    #[test]
+    fn truncated() {
+        let err = Part1::parse("3   4\n4").err().unwrap().to_string();
+
+        assert_eq!(err, "2:1: expected 2 location ids per line, found 1 '4'");
+    }
+
+    #[test]
+    fn ragged() {
+        let input = "1 2 3\n4 5\n\n6 7 8\n";
+        let lists = Lists::parse(input, false).unwrap();
+
+        assert_eq!(lists.columns(), &[vec![1, 4, 6], vec![2, 5, 7], vec![3, 8]]);
+        assert_eq!(lists.ragged().len(), 1);
+        assert_eq!(lists.ragged()[0].to_string(), "2:1: expected 3 location ids per line, found 2 '4 5'");
+        assert_eq!(lists.pair(0, 2).unwrap().total(), 6);
+
+        assert_eq!(Lists::parse(input, true).unwrap_err().to_string(), "2:1: expected 3 location ids per line, found 2 '4 5'");
+        assert_eq!(Lists::parse("1 2\n3 4 5\n6 7\n", false).unwrap_err().to_string(), "2:1: expected 2 location ids per line, found 3 '3 4 5'");
+        assert_eq!(Part1::parse(input).unwrap_err().to_string(), "2:1: expected 3 location ids per line, found 2 '4 5'");
+        assert_eq!(Part1::parse("1 2 3\n").unwrap_err().to_string(), "expected a left and a right list, found 3 lists");
+    }
+
+    #[test]
+    fn report() {
+        let report = Part1::parse(include_str!("../test.txt")).unwrap().report(1).unwrap();
+
+        assert_eq!((report.pairs, report.min, report.max, report.median), (6, 0, 5, 1.5));
+        assert_eq!(report.percentiles, vec![(10, 0), (25, 1), (50, 1), (75, 2), (90, 5), (99, 5)]);
+        assert_eq!(report.histogram, vec![(0, 1), (1, 2), (2, 2), (5, 1)]);
+        assert_eq!(report.shared, vec![Shared { id: 3, left: 3, right: 3 }]);
+
+        assert_eq!(report.json(), concat!(
+            r#"{"pairs":6,"min":0,"max":5,"mean":1.8333333333333333,"median":1.5,"#,
+            r#""percentiles":{"p10":0,"p25":1,"p50":1,"p75":2,"p90":5,"p99":5},"#,
+            r#""histogram":[{"distance":0,"count":1},{"distance":1,"count":2},{"distance":2,"count":2},{"distance":5,"count":1}],"#,
+            r#""shared":[{"id":3,"left":3,"right":3}]}"#,
+        ));
+
+        assert!(report.to_string().starts_with("6 pairs, distance min 0 max 5 mean 1.83 median 1.5\np10 0, p25 1"));
+        assert!(report.to_string().contains("  1      2 ########################################\n"));
+        assert_eq!(Part1::parse("1 2\n").unwrap().report(3).unwrap().shared, vec![]);
+    }
+
+    #[test]
+    fn short_first_line() {
+        let input = "1\n2 3\n4 5\n";
+        let lists = Lists::parse(input, false).unwrap();
+
+        assert_eq!(lists.columns(), &[vec![1, 2, 4], vec![3, 5]]);
+        assert_eq!(lists.ragged()[0].to_string(), "1:1: expected 2 location ids per line, found 1 '1'");
+        assert_eq!(Lists::parse(input, true).unwrap_err().to_string(), "1:1: expected 2 location ids per line, found 1 '1'");
+    }
+
+    #[test]
+    fn histogram() {
+        let part2 = Part2::from(Part1::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap());
+        let histogram = part2.histogram();
+
+        assert_eq!((histogram.left(3), histogram.right(3)), (3, 3));
+        assert_eq!((histogram.left(7), histogram.right(9)), (0, 1));
+        assert_eq!(histogram.shared(), vec![3, 4]);
+        assert_eq!(histogram.only_left(), vec![1, 2]);
+        assert_eq!(histogram.only_right(), vec![5, 9]);
+        assert_eq!(part2.similarity(), 31);
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use aoc::animate::{Animation, Cell, Colour, Frame};
+use aoc::image::{Image, Rgb};
+use aoc::random::Rng;
+use aoc::{debug, search, Answer, Grid, Point, Solution};
+
+const SUMMIT: i32 = 9;
+
+
+/// Neighbours exactly one step higher, trails only ever go up so the graph has no cycles.
+fn climb(map: &Grid<i32>, position: Point) -> impl Iterator<Item = Point> + '_ {
+    map.neighbours4(position).filter(move |new| map[*new] - map[position] == 1)
+}
+
+
+#[derive(Clone)]
+pub struct Part1 {
+    map: Grid<i32>,
+    heads: Vec<Point>,
+}
+
+impl Part1 {
+    /// Every cell on a trail that starts at `head`.
+    fn trails(&self, head: Point) -> HashSet<Point> {
+        search::reachable(head, |position| climb(&self.map, *position))
+    }
+
+    fn score(&self, head: Point) -> usize {
+        self.trails(head)
+            .iter()
+            .filter(|position| self.map[**position] == SUMMIT)
+            .count()
+    }
+
+    pub fn calculate(&self) -> usize {
+        debug!("heads: {:?}", self.heads);
+
+        self.heads.iter()
+            .map(|head| self.score(*head))
+            .sum()
+    }
+}
+
+/// The trails of one trailhead per frame, with the summits they reach picked out.
+pub struct Trails<'a> {
+    part1: &'a Part1,
+    next: usize,
+}
+
+impl Animation for Trails<'_> {
+    fn frame(&mut self) -> Option<Frame> {
+        let head = *self.part1.heads.get(self.next)?;
+        let trails = self.part1.trails(head);
+        let summits = trails.iter().copied().filter(|position| self.part1.map[*position] == SUMMIT).collect::<Vec<Point>>();
+
+        self.next += 1;
+
+        let frame = Frame::new(&self.part1.map, |height| Cell::new(char::from_digit(*height as u32, 10).unwrap_or('?'), Colour::Grey))
+            .paint(trails, Colour::Green)
+            .paint(summits.iter().copied(), Colour::Yellow)
+            .mark(head, Cell::new('^', Colour::Red))
+            .caption(format!("trailhead {} of {} at {},{} reaches {} summits", self.next, self.part1.heads.len(), head.x, head.y, summits.len()));
+
+        Some(frame)
+    }
+}
+
+pub struct Part2 {
+    map: Grid<i32>,
+    heads: Vec<Point>,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            map: part1.map,
+            heads: part1.heads,
+        }
+    }
+}
+
+impl Part2 {
+    fn rate(&self, head: Point) -> u64 {
+        search::count_paths(head, |position| climb(&self.map, *position), |position| self.map[*position] == SUMMIT)
+    }
+
+    pub fn calculate(&self) -> u64 {
+        self.heads.iter()
+            .map(|head| self.rate(*head))
+            .sum()
+    }
+}
+
+/// A `size` by `size` map of random heights with a trail carved in for about every fifty cells.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, (0..size * size).map(|_| rng.below(10) as i32).collect());
+
+    for _ in 0..(size * size / 50).max(1) {
+        let mut position = Point::from_grid(rng.below(size as u64) as usize, rng.below(size as u64) as usize);
+
+        for height in 0..=SUMMIT {
+            map[position] = height;
+
+            let next = map.neighbours4(position).collect::<Vec<Point>>();
+
+            if next.is_empty() {
+                break;
+            }
+
+            position = *rng.pick(&next);
+        }
+    }
+
+    map.to_string()
+}
+
+/// Follows every trail from every trailhead by plain recursion, keeping every summit it arrives at.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    fn climb(map: &[Vec<u32>], x: usize, y: usize, summits: &mut Vec<(usize, usize)>) {
+        if map[y][x] == 9 {
+            summits.push((x, y));
+
+            return;
+        }
+
+        let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+
+        for (nx, ny) in neighbours {
+            if map.get(ny).and_then(|row| row.get(nx)) == Some(&(map[y][x] + 1)) {
+                climb(map, nx, ny, summits);
+            }
+        }
+    }
+
+    let map = input.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().map(|c| c.to_digit(10).ok_or("expected a height")).collect::<Result<Vec<u32>, _>>())
+        .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+    if map.is_empty() || map.iter().any(|row| row.len() != map[0].len()) {
+        return Err("expected a rectangle of heights".into());
+    }
+
+    let mut scores = 0;
+    let mut ratings = 0;
+
+    for (y, row) in map.iter().enumerate() {
+        for x in (0..row.len()).filter(|x| row[*x] == 0) {
+            let mut summits = Vec::new();
+
+            climb(&map, x, y, &mut summits);
+
+            ratings += summits.len();
+
+            summits.sort();
+            summits.dedup();
+
+            scores += summits.len();
+        }
+    }
+
+    Ok([scores.into(), ratings.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let map = aoc::parse::digit_grid::<i32>(input, "height digit")?;
+
+        let heads = map.iter()
+            .filter(|(_, height)| **height == 0)
+            .map(|(position, _)| position)
+            .collect::<Vec<Point>>();
+
+        Ok(Part1 {
+            map,
+            heads,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+
+    fn animation(&self) -> Option<Box<dyn Animation + '_>> {
+        Some(Box::new(Trails {
+            part1: self,
+            next: 0,
+        }))
+    }
+
+    /// Heights from dark valleys to bright summits, trailheads in red.
+    fn image(&self) -> Option<Image> {
+        let mut map = self.map.map(|height| Rgb(10, 30, 20).mix(Rgb(220, 255, 220), *height as f64 / SUMMIT as f64));
+
+        for head in self.heads.iter() {
+            map[*head] = Rgb(220, 50, 47);
+        }
+
+        Some(Image::new(&map, 4, |pixel| *pixel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Part1::parse("0123\n1.34\n").err().unwrap().to_string(), "2:2: expected a height digit '.'");
+        assert_eq!(Part1::parse("0123\n123\n").err().unwrap().to_string(), "2:1: expected a row of width 4 '123'");
+    }
+
+    #[test]
+    fn trails() {
+        let part1 = Part1::parse("0123\n1234\n8765\n9876\n").unwrap();
+        let mut trails = part1.animation().unwrap();
+        let frame = trails.frame().unwrap();
+
+        assert_eq!(frame.render(false), "^123\n1234\n8765\n9876\ntrailhead 1 of 1 at 0,0 reaches 1 summits\n");
+        assert_eq!(frame.cells()[Point::new(0, 3)].colour, Colour::Yellow);
+        assert!(trails.frame().is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use aoc::random::Rng;
+use aoc::{Answer, Solution};
+
+
+pub struct Part1 {
+    stones: Vec<usize>,
+}
+
+impl Part1 {
+    fn to_digits(number: usize) -> Vec<char> {
+        number.to_string()
+            .chars()
+            .collect::<Vec<char>>()
+    }
+
+    fn from_digits(digits: &[char]) -> usize {
+        digits.iter()
+            .collect::<String>()
+            .parse::<usize>()
+            .unwrap_or(0)
+    }
+
+    fn update(&self, stone: usize) -> Vec<usize> {
+        let digits = Part1::to_digits(stone);
+
+        match stone {
+            0 => vec![1],
+            _ => match digits.len() % 2 {
+                0 => vec![Part1::from_digits(&digits[..digits.len() / 2]), Part1::from_digits(&digits[digits.len() / 2..])],
+                _ => vec![stone * 2024],
+            },
+        }
+    }
+
+    // stones never interact, so we only need to know how many of each number there are
+    fn blink(&self, stones: HashMap<usize, usize>) -> HashMap<usize, usize> {
+        stones.iter()
+            .flat_map(|(stone, count)| self.update(*stone).into_iter().map(move |stone| (stone, *count)))
+            .fold(HashMap::new(), |mut acc, (stone, count)| {
+                *acc.entry(stone).or_insert(0) += count;
+
+                acc
+            })
+    }
+
+    pub fn calculate(&self, times: usize) -> usize {
+        let mut stones = self.stones.iter()
+            .fold(HashMap::new(), |mut acc, stone| {
+                *acc.entry(*stone).or_insert(0) += 1;
+
+                acc
+            });
+
+        for _ in 0..times {
+            stones = self.blink(stones);
+        }
+
+        stones.values().sum()
+    }
+}
+
+/// `size` stones engraved with numbers of up to six digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones = (0..size.max(1)).map(|_| rng.below(1_000_000).to_string()).collect::<Vec<String>>();
+
+    format!("{}\n", stones.join(" "))
+}
+
+/// Blinks the whole row of stones for part 1, part 2 counts the stones every single stone turns into.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    fn blink(stone: u64) -> Vec<u64> {
+        let digits = stone.checked_ilog10().map_or(1, |log| log + 1);
+
+        if stone == 0 {
+            vec![1]
+        } else if digits.is_multiple_of(2) {
+            let half = 10u64.pow(digits / 2);
+
+            vec![stone / half, stone % half]
+        } else {
+            vec![stone * 2024]
+        }
+    }
+
+    fn count(stone: u64, blinks: usize, memo: &mut HashMap<(u64, usize), u64>) -> u64 {
+        if blinks == 0 {
+            return 1;
+        }
+
+        if let Some(count) = memo.get(&(stone, blinks)) {
+            return *count;
+        }
+
+        let total = blink(stone).into_iter().map(|stone| count(stone, blinks - 1, memo)).sum();
+
+        memo.insert((stone, blinks), total);
+
+        total
+    }
+
+    let stones = input.split_whitespace().map(|stone| stone.parse::<u64>()).collect::<Result<Vec<u64>, _>>()?;
+    let mut row = stones.clone();
+
+    for _ in 0..25 {
+        row = row.into_iter().flat_map(blink).collect();
+    }
+
+    let mut memo = HashMap::new();
+    let total = stones.iter().map(|stone| count(*stone, 75, &mut memo)).sum::<u64>();
+
+    Ok([row.len().into(), total.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        Ok(Part1 {
+            stones: aoc::parse::numbers(input, input, "stone")?,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate(25).into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate(75).into())
+    }
+}
@@ -0,0 +1,455 @@
+use std::fmt;
+
+use aoc::random::Rng;
+use aoc::{Answer, ParseError, Part, Solution};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+impl Direction {
+    pub const BOTH: &'static [Direction] = &[Direction::Increasing, Direction::Decreasing];
+
+    /// How far `a` to `b` goes this way, negative when it goes the other way.
+    fn step(&self, a: i32, b: i32) -> i64 {
+        match self {
+            Direction::Increasing => b as i64 - a as i64,
+            Direction::Decreasing => a as i64 - b as i64,
+        }
+    }
+}
+
+/// Why a pair of neighbouring levels isn't safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    WrongDirection,
+    TooSmall,
+    TooLarge,
+    Flat,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rule = match self {
+            Rule::WrongDirection => "wrong direction",
+            Rule::TooSmall => "step too small",
+            Rule::TooLarge => "step too large",
+            Rule::Flat => "flat step",
+        };
+
+        write!(f, "{}", rule)
+    }
+}
+
+/// The levels at `index` and `index + 1` break `rule`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub index: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    Dampened(Vec<usize>),
+    /// The first pair that goes wrong, in whichever direction the report keeps to the longest.
+    Unsafe(Violation),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Safe => write!(f, "safe"),
+            Verdict::Dampened(removed) if removed.len() == 1 => write!(f, "safe after removing the level at index {}", removed[0]),
+            Verdict::Dampened(removed) => {
+                let removed = removed.iter().map(|index| index.to_string()).collect::<Vec<String>>();
+
+                write!(f, "safe after removing the levels at indices {}", removed.join(", "))
+            },
+            Verdict::Unsafe(violation) => write!(f, "unsafe, {} between index {} and {}", violation.rule, violation.index, violation.index + 1),
+        }
+    }
+}
+
+/// What makes a report safe, every pair of neighbouring levels has to go the same allowed
+/// direction by a step within `min_step..=max_step`, after removing up to `tolerance` levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub directions: &'static [Direction],
+    pub tolerance: usize,
+}
+
+impl SafetyPolicy {
+    /// The rules of part 1.
+    pub const STRICT: SafetyPolicy = SafetyPolicy {
+        min_step: 1,
+        max_step: 3,
+        directions: Direction::BOTH,
+        tolerance: 0,
+    };
+
+    /// The rules of part 2, the problem dampener takes out a single bad level.
+    pub const DAMPENED: SafetyPolicy = SafetyPolicy {
+        tolerance: 1,
+        ..SafetyPolicy::STRICT
+    };
+
+    fn allows(&self, direction: Direction, a: i32, b: i32) -> bool {
+        (self.min_step as i64..=self.max_step as i64).contains(&direction.step(a, b))
+    }
+
+    /// The rule `a` to `b` breaks going `direction`, if any.
+    fn broken(&self, direction: Direction, a: i32, b: i32) -> Option<Rule> {
+        let step = direction.step(a, b);
+
+        if self.allows(direction, a, b) {
+            None
+        } else if step == 0 {
+            Some(Rule::Flat)
+        } else if step < 0 {
+            Some(Rule::WrongDirection)
+        } else if step < self.min_step as i64 {
+            Some(Rule::TooSmall)
+        } else {
+            Some(Rule::TooLarge)
+        }
+    }
+
+    fn violation(&self, levels: &[i32], direction: Direction) -> Option<Violation> {
+        levels.windows(2)
+            .enumerate()
+            .find_map(|(index, pair)| self.broken(direction, pair[0], pair[1]).map(|rule| Violation { index, rule }))
+    }
+
+    /// For every level the fewest removals before it that leave a safe run ending in it,
+    /// a run can only skip back over `tolerance` levels.
+    fn fewest(&self, levels: &[i32], direction: Direction) -> Vec<usize> {
+        let mut fewest: Vec<usize> = Vec::with_capacity(levels.len());
+
+        for (index, level) in levels.iter().enumerate() {
+            let mut best = index;
+
+            for previous in index.saturating_sub(self.tolerance + 1)..index {
+                let removed = fewest[previous] + index - previous - 1;
+
+                if removed < best && self.allows(direction, levels[previous], *level) {
+                    best = removed;
+                }
+            }
+
+            fewest.push(best);
+        }
+
+        fewest
+    }
+
+    /// The last level to keep and how many levels go in total, if that is within the tolerance.
+    fn end(&self, fewest: &[usize]) -> Option<(usize, usize)> {
+        fewest.iter()
+            .enumerate()
+            .map(|(index, removed)| (index, removed + fewest.len() - 1 - index))
+            .min_by_key(|(_, removed)| *removed)
+            .filter(|(_, removed)| *removed <= self.tolerance)
+    }
+
+    /// The fewest levels to remove so the rest goes `direction` safely, if that is within the tolerance.
+    fn removals_towards(&self, levels: &[i32], direction: Direction) -> Option<usize> {
+        self.end(&self.fewest(levels, direction)).map(|(_, removed)| removed)
+    }
+
+    /// Which levels to remove so the rest goes `direction` safely, walking the runs back from their end.
+    fn removed_towards(&self, levels: &[i32], direction: Direction) -> Option<Vec<usize>> {
+        let fewest = self.fewest(levels, direction);
+        let (mut index, _) = self.end(&fewest)?;
+        let mut removed = (index + 1..levels.len()).collect::<Vec<usize>>();
+
+        loop {
+            let previous = (index.saturating_sub(self.tolerance + 1)..index)
+                .rev()
+                .find(|previous| fewest[*previous] + index - previous - 1 == fewest[index] && self.allows(direction, levels[*previous], levels[index]));
+
+            let Some(previous) = previous else {
+                removed.extend(0..index);
+                break;
+            };
+
+            removed.extend(previous + 1..index);
+            index = previous;
+        }
+
+        removed.sort_unstable();
+
+        Some(removed)
+    }
+
+    /// The fewest levels to remove to make `levels` safe, `None` if it takes more than the tolerance.
+    /// Takes `O(n * tolerance)` for each allowed direction.
+    pub fn removals(&self, levels: &[i32]) -> Option<usize> {
+        self.directions.iter()
+            .filter_map(|direction| self.removals_towards(levels, *direction))
+            .min()
+    }
+
+    pub fn explain(&self, levels: &[i32]) -> Verdict {
+        let removed = self.directions.iter()
+            .filter_map(|direction| self.removed_towards(levels, *direction))
+            .min_by_key(|removed| removed.len());
+
+        match removed {
+            Some(removed) if removed.is_empty() => Verdict::Safe,
+            Some(removed) => Verdict::Dampened(removed),
+            None => {
+                let violation = self.directions.iter()
+                    .filter_map(|direction| self.violation(levels, *direction))
+                    .reduce(|best, violation| if violation.index > best.index { violation } else { best });
+
+                // without a single allowed direction every pair goes the wrong way
+                Verdict::Unsafe(violation.unwrap_or(Violation { index: 0, rule: Rule::WrongDirection }))
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    levels: Vec<i32>,
+}
+
+impl Report {
+    pub fn parse(input: &str, report: &str) -> Result<Report, ParseError> {
+        let levels = aoc::parse::numbers::<i32>(input, report, "level")?;
+
+        if levels.len() < 2 {
+            return Err(ParseError::at(input, report, "report needs at least two levels"));
+        }
+
+        Ok(Report::new(levels))
+    }
+
+    pub fn new(levels: Vec<i32>) -> Report {
+        Report {
+            levels,
+        }
+    }
+
+    pub fn levels(&self) -> &[i32] {
+        &self.levels
+    }
+
+    pub fn is_safe(&self, policy: &SafetyPolicy) -> bool {
+        policy.removals(&self.levels).is_some()
+    }
+
+    pub fn explain(&self, policy: &SafetyPolicy) -> Verdict {
+        policy.explain(&self.levels)
+    }
+}
+
+pub struct Data {
+    reports: Vec<Report>,
+}
+
+impl Data {
+    /// How many reports are safe under `policy`.
+    pub fn count(&self, policy: &SafetyPolicy) -> usize {
+        self.reports.iter()
+            .filter(|report| report.is_safe(policy))
+            .count()
+    }
+
+    pub fn explain(&self, policy: &SafetyPolicy) -> Vec<Verdict> {
+        self.reports.iter()
+            .map(|report| report.explain(policy))
+            .collect()
+    }
+
+    pub fn safe(&self) -> usize {
+        self.count(&SafetyPolicy::STRICT)
+    }
+
+    pub fn analyze(&self) -> usize {
+        self.count(&SafetyPolicy::DAMPENED)
+    }
+}
+
+/// `size` reports of five to eight levels that start out safe, with up to two levels knocked off course.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        let increasing = rng.chance(0.5);
+        let mut level = rng.between(30, 70) as i32;
+        let mut levels = Vec::new();
+
+        for _ in 0..rng.between(5, 8) {
+            let step = rng.between(1, 3) as i32;
+
+            levels.push(level);
+            level += if increasing { step } else { -step };
+        }
+
+        for _ in 0..rng.below(3) {
+            let index = rng.below(levels.len() as u64) as usize;
+
+            levels[index] += rng.between(0, 8) as i32 - 4;
+        }
+
+        text.push_str(&levels.iter().map(|level| level.to_string()).collect::<Vec<String>>().join(" "));
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Every report checked as it is, and again with each of its levels left out in turn.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let safe = |levels: &[i64]| {
+        levels.windows(2).all(|pair| (1..=3).contains(&(pair[1] - pair[0])))
+            || levels.windows(2).all(|pair| (1..=3).contains(&(pair[0] - pair[1])))
+    };
+
+    let mut strict = 0;
+    let mut dampened = 0;
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let levels = line.split_whitespace().map(|level| level.parse::<i32>().map(i64::from)).collect::<Result<Vec<i64>, _>>()?;
+
+        if levels.len() < 2 {
+            return Err("a report needs at least two levels".into());
+        }
+
+        let without = |skip: usize| levels.iter().enumerate().filter(|(index, _)| *index != skip).map(|(_, level)| *level).collect::<Vec<i64>>();
+
+        if safe(&levels) {
+            strict += 1;
+        }
+
+        if safe(&levels) || (0..levels.len()).any(|skip| safe(&without(skip))) {
+            dampened += 1;
+        }
+    }
+
+    Ok([strict.into(), dampened.into()])
+}
+
+impl Solution for Data {
+    fn parse(input: &str) -> aoc::Result<Data> {
+        Ok(Data {
+            reports: aoc::parse::lines(input)
+                .map(|line| Report::parse(input, line))
+                .collect::<Result<Vec<Report>, ParseError>>()?,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.safe().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(self.analyze().into())
+    }
+
+    /// The verdict on every report, part 2 with the dampener.
+    fn explain(&self, part: Part) -> Option<String> {
+        let policy = match part {
+            Part::One => SafetyPolicy::STRICT,
+            Part::Two => SafetyPolicy::DAMPENED,
+        };
+
+        let verdicts = self.explain(&policy)
+            .iter()
+            .enumerate()
+            .map(|(index, verdict)| format!("report {}: {}\n", index + 1, verdict))
+            .collect();
+
+        Some(verdicts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        let err = Data::parse("7 6 4\n1 2 y 8\n").err().unwrap().to_string();
+
+        assert_eq!(err, "2:5: invalid level 'y'");
+    }
+
+    #[test]
+    fn single_level() {
+        let err = Data::parse("7 6 4\n1\n").err().unwrap().to_string();
+
+        assert_eq!(err, "2:1: report needs at least two levels '1'");
+    }
+
+    #[test]
+    fn two_levels() {
+        let data = Data::parse("1 9\n").unwrap();
+
+        assert_eq!(data.safe(), 0);
+        assert_eq!(data.analyze(), 1);
+    }
+
+    #[test]
+    fn policy() {
+        let policy = SafetyPolicy { tolerance: 2, ..SafetyPolicy::STRICT };
+
+        assert_eq!(policy.removals(&[1, 2, 9, 9, 3, 4]), Some(2));
+        assert_eq!(SafetyPolicy::DAMPENED.removals(&[1, 2, 9, 9, 3, 4]), None);
+        assert_eq!(SafetyPolicy::DAMPENED.removals(&[9, 1, 2, 3]), Some(1));
+        assert_eq!(SafetyPolicy::DAMPENED.removals(&[5, 4, 3, 2]), Some(0));
+
+        let rising = SafetyPolicy {
+            min_step: 0,
+            max_step: 5,
+            directions: &[Direction::Increasing],
+            tolerance: 0,
+        };
+
+        assert_eq!(rising.removals(&[1, 1, 6, 8]), Some(0));
+        assert_eq!(rising.removals(&[5, 4, 3, 2]), None);
+        assert_eq!(rising.removals(&[i32::MIN, i32::MAX]), None);
+    }
+
+    #[test]
+    fn explain() {
+        let data = Data::parse(include_str!("../test.txt")).unwrap();
+        let unsafe_at = |index, rule| Verdict::Unsafe(Violation { index, rule });
+
+        assert_eq!(data.explain(&SafetyPolicy::STRICT), vec![
+            Verdict::Safe,
+            unsafe_at(1, Rule::TooLarge),
+            unsafe_at(2, Rule::TooLarge),
+            unsafe_at(1, Rule::WrongDirection),
+            unsafe_at(2, Rule::Flat),
+            Verdict::Safe,
+        ]);
+
+        assert_eq!(data.explain(&SafetyPolicy::DAMPENED), vec![
+            Verdict::Safe,
+            unsafe_at(1, Rule::TooLarge),
+            unsafe_at(2, Rule::TooLarge),
+            Verdict::Dampened(vec![1]),
+            Verdict::Dampened(vec![2]),
+            Verdict::Safe,
+        ]);
+
+        let policy = SafetyPolicy { min_step: 2, tolerance: 2, ..SafetyPolicy::STRICT };
+
+        assert_eq!(policy.explain(&[1, 3, 9, 9, 5, 7]), Verdict::Dampened(vec![2, 3]));
+        assert_eq!(policy.explain(&[1, 2, 3, 4]), Verdict::Dampened(vec![1, 3]));
+        assert_eq!(SafetyPolicy { tolerance: 0, ..policy }.explain(&[1, 2, 3, 4]), unsafe_at(0, Rule::TooSmall));
+        assert_eq!(unsafe_at(1, Rule::Flat).to_string(), "unsafe, flat step between index 1 and 2");
+        assert_eq!(Verdict::Dampened(vec![3]).to_string(), "safe after removing the level at index 3");
+        assert_eq!(Verdict::Dampened(vec![0, 4]).to_string(), "safe after removing the levels at indices 0, 4");
+        assert_eq!(Solution::explain(&data, Part::Two).unwrap().lines().nth(3), Some("report 4: safe after removing the level at index 1"));
+    }
+}
@@ -0,0 +1,215 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use aoc::random::Rng;
+use aoc::{Answer, Solution};
+
+// the arguments of `mul` are one to three digits, anything longer is corrupted
+const DIGITS: usize = 3;
+
+const JUNK: &[&str] = &[
+    "mul", "mul(", "do", "don't", "(", ")", ",", " ", "'", "what()", "select()", "from()", "how()", "who()",
+    "!", "@", "#", "$", "%", "^", "&", "*", "[", "]", "{", "}", "<", ">", "+", "-", "/", ":", ";", "?", "~",
+];
+
+
+#[derive(Debug, Clone)]
+pub enum State {
+    Normal,
+    Dont,
+    Arg1,
+    Arg2(usize),
+}
+
+#[derive(Clone)]
+pub struct Memory {
+    memory: Peekable<IntoIter<char>>,
+    state: State,
+    should_close: bool,
+}
+
+impl Memory {
+    fn take_while<F>(&mut self, f: F) -> String
+    where
+        F: Copy + Fn(&(usize, char)) -> bool
+    {
+        let mut characters: Vec<char> = Vec::new();
+        let mut index = 0;
+
+        while let Some(c) = self.memory.by_ref().next_if(|c| f(&(index, *c))) {
+            characters.push(c);
+
+            index += 1;
+        }
+
+        characters.iter().collect::<String>()
+    }
+
+    fn state_if_match(&mut self, needle: &str, state: State, except: State) {
+        let characters = needle.chars().collect::<Vec<char>>();
+
+        let token = self.take_while(|(index, c)| *index < characters.len() && *c == characters[*index]);
+
+        self.state = if token.as_str() == needle { state } else { except };
+    }
+
+    pub fn interpret(&mut self, conditionals: bool) -> usize {
+        let mut result = 0;
+
+        while !self.should_close {
+            match self.state {
+                State::Normal => {
+                    match self.memory.next() {
+                        Some('m') => self.state_if_match("ul(", State::Arg1, State::Normal),
+                        Some('d') if conditionals => self.state_if_match("on't()", State::Dont, State::Normal),
+                        None => self.should_close = true,
+                        _ => {},
+                    }
+                },
+                State::Dont => {
+                    match self.memory.next() {
+                        Some('d') => self.state_if_match("o()", State::Normal, State::Dont),
+                        None => self.should_close = true,
+                        _ => {},
+                    }
+                },
+                // corrupted arguments are just noise, the delimiter is only consumed when it
+                // matches so that something like `mul(2mul(3,4)` still finds the second instruction
+                State::Arg1 => {
+                    let value = self.take_while(|(index, c)| *index < DIGITS && c.is_ascii_digit()).parse::<usize>();
+
+                    match value.ok().zip(self.memory.next_if_eq(&',')) {
+                        Some((value, _)) => self.state = State::Arg2(value),
+                        None => self.state = State::Normal,
+                    }
+                },
+                State::Arg2(arg1) => {
+                    let value = self.take_while(|(index, c)| *index < DIGITS && c.is_ascii_digit()).parse::<usize>();
+
+                    if let Some((value, _)) = value.ok().zip(self.memory.next_if_eq(&')')) {
+                        result += arg1 * value;
+                    }
+
+                    self.state = State::Normal;
+                },
+            }
+        }
+
+        result
+    }
+}
+
+/// About `size` instructions on one line, real `mul`, `do()` and `don't()` between junk and broken ones.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut memory = String::new();
+
+    for _ in 0..size.max(1) {
+        for _ in 0..rng.below(4) {
+            memory.push_str(rng.pick::<&str>(JUNK));
+        }
+
+        // mostly valid arguments, with the odd one that is too long or missing
+        let mut argument = || match rng.below(10) {
+            0 => String::new(),
+            1 => rng.between(1000, 99999).to_string(),
+            _ => rng.between(1, 999).to_string(),
+        };
+
+        let (a, b) = (argument(), argument());
+
+        match rng.below(10) {
+            0 => memory.push_str("do()"),
+            1 => memory.push_str("don't()"),
+            2 => memory.push_str(&format!("mul({},{}", a, b)),
+            3 => memory.push_str(&format!("mul({}, {})", a, b)),
+            4 => memory.push_str(&format!("mul[{},{}]", a, b)),
+            _ => memory.push_str(&format!("mul({},{})", a, b)),
+        }
+    }
+
+    memory.push('\n');
+    memory
+}
+
+/// Tries to read an instruction at every offset, numbers have one to three digits.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let bytes = input.as_bytes();
+
+    let number = |at: usize| -> Option<(usize, usize)> {
+        let digits = bytes.get(at..)?.iter().take_while(|byte| byte.is_ascii_digit()).count();
+
+        (1..=3).contains(&digits).then_some(())?;
+
+        Some((input.get(at..at + digits)?.parse().ok()?, at + digits))
+    };
+
+    let mul = |at: usize| -> Option<usize> {
+        let (a, at) = number(at + 4)?;
+        let (b, at) = number(at + 1).filter(|_| bytes.get(at) == Some(&b','))?;
+
+        (bytes.get(at) == Some(&b')')).then_some(a * b)
+    };
+
+    let mut all = 0;
+    let mut enabled = 0;
+    let mut on = true;
+
+    for at in 0..bytes.len() {
+        if bytes[at..].starts_with(b"do()") {
+            on = true;
+        } else if bytes[at..].starts_with(b"don't()") {
+            on = false;
+        } else if let Some(product) = Some(at).filter(|at| bytes[*at..].starts_with(b"mul(")).and_then(mul) {
+            all += product;
+
+            if on {
+                enabled += product;
+            }
+        }
+    }
+
+    Ok([all.into(), enabled.into()])
+}
+
+impl Solution for Memory {
+    fn parse(input: &str) -> aoc::Result<Memory> {
+        let memory = input.chars()
+            .collect::<Vec<char>>()
+            .into_iter()
+            .peekable();
+
+        Ok(Memory {
+            memory,
+            state: State::Normal,
+            should_close: false,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.clone().interpret(false).into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(self.clone().interpret(true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrupted_arguments() {
+        let memory = Memory::parse("mul(,mul(2mul(3,4)mul(5,)mul(6,7)").unwrap();
+
+        assert_eq!(memory.part1().unwrap().to_string(), "54");
+    }
+
+    #[test]
+    fn long_arguments() {
+        let memory = Memory::parse("mul(1234,2)mul(2,3)mul(4,5678)mul(,1)").unwrap();
+
+        assert_eq!(memory.part1().unwrap().to_string(), "6");
+        assert_eq!(reference("mul(1234,2)mul(2,3)mul(4,5678)mul(,1)").unwrap()[0], 6u64.into());
+    }
+}
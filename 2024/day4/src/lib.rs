@@ -0,0 +1,131 @@
+use aoc::random::Rng;
+use aoc::{trace, Answer, Direction, Grid, Point, Solution};
+
+const NEEDLE: [char; 4] = ['X', 'M', 'A', 'S'];
+
+const MAS_NEEDLE: [char; 3] = ['M', 'A', 'S'];
+const MAS_REVERSE: [char; 3] = ['S', 'A', 'M'];
+
+
+#[derive(Clone)]
+pub struct Part1 {
+    grid: Grid<char>,
+}
+
+impl Part1 {
+    fn scan(&self, point: Point, direction: Direction) -> bool {
+        let result = self.grid.ray(point, direction.vector())
+            .map(|point| self.grid[point])
+            .take(NEEDLE.len())
+            .eq(NEEDLE);
+
+        if result {
+            trace!("[scan] direction={:?}, pos={:?}, result={}", direction, point, result);
+        }
+
+        result
+    }
+
+    pub fn calculate(&self) -> usize {
+        self.grid.iter()
+            .filter(|(_, cell)| **cell == NEEDLE[0])
+            .map(|(point, _)| Direction::ALL.iter().filter(|direction| self.scan(point, **direction)).count())
+            .sum()
+    }
+}
+
+pub struct Part2 {
+    grid: Grid<char>,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            grid: part1.grid,
+        }
+    }
+}
+
+impl Part2 {
+    fn diagonal(&self, point: Point, direction: Direction) -> Option<[char; 3]> {
+        let start = self.grid.step(point, direction.opposite())?;
+        let end = self.grid.step(point, direction)?;
+
+        Some([self.grid[start], self.grid[point], self.grid[end]])
+    }
+
+    fn scan(&self, point: Point) -> bool {
+        let right = self.diagonal(point, Direction::DownRight);
+        let down = self.diagonal(point, Direction::DownLeft);
+
+        trace!("[scan] right={:?}, down={:?}, pos={:?}", right, down, point);
+
+        matches!(right, Some(MAS_NEEDLE | MAS_REVERSE)) && matches!(down, Some(MAS_NEEDLE | MAS_REVERSE))
+    }
+
+    // every X is centered on an 'A', diagonals that leave the grid are no match
+    pub fn calculate(&self) -> usize {
+        self.grid.iter()
+            .filter(|(point, cell)| **cell == MAS_NEEDLE[1] && self.scan(*point))
+            .count()
+    }
+}
+
+/// A `size` by `size` word search of the letters in XMAS.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| (0..size).map(|_| *rng.pick(&NEEDLE)).chain(['\n']).collect::<String>())
+        .collect()
+}
+
+/// Reads the word in all eight directions from every cell, on plain rows of characters.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let rows = input.lines().filter(|line| !line.trim().is_empty()).map(|line| line.chars().collect::<Vec<char>>()).collect::<Vec<Vec<char>>>();
+
+    if rows.is_empty() || rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err("expected a rectangle of letters".into());
+    }
+
+    let at = |x: i64, y: i64| -> Option<char> {
+        rows.get(usize::try_from(y).ok()?)?.get(usize::try_from(x).ok()?).copied()
+    };
+
+    let mut words = 0;
+    let mut crosses = 0;
+
+    for y in 0..rows.len() as i64 {
+        for x in 0..rows[0].len() as i64 {
+            for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                if "XMAS".chars().enumerate().all(|(step, letter)| at(x + dx * step as i64, y + dy * step as i64) == Some(letter)) {
+                    words += 1;
+                }
+            }
+
+            let diagonal = |dx: i64| [at(x - dx, y - 1), at(x + dx, y + 1)];
+
+            if at(x, y) == Some('A') && [diagonal(1), diagonal(-1)].iter().all(|ends| matches!(ends, [Some('M'), Some('S')] | [Some('S'), Some('M')])) {
+                crosses += 1;
+            }
+        }
+    }
+
+    Ok([words.into(), crosses.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        Ok(Part1 {
+            grid: Grid::chars(input)?,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+}
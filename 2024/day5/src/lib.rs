@@ -0,0 +1,275 @@
+use aoc::random::Rng;
+use aoc::{trace, Answer, ParseError, Solution};
+
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    left: usize,
+    right: usize,
+}
+
+impl Rule {
+    pub fn parse(input: &str, line: &str) -> Result<Rule, ParseError> {
+        let (left, right) = line.split_once('|')
+            .ok_or_else(|| ParseError::at(input, line, "expected a rule like 47|53"))?;
+
+        Ok(Rule {
+            left: aoc::error::parse::<usize>(input, left.trim(), "page")?,
+            right: aoc::error::parse::<usize>(input, right.trim(), "page")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+impl RuleSet {
+    pub fn new(rules: Vec<Rule>) -> RuleSet {
+        RuleSet {
+            rules,
+        }
+    }
+
+    pub fn sort(&self, update: &mut Update) {
+        trace!("[sort] update={:?}", update);
+
+        while !self.validate(update) {
+            for (index, page) in update.pages.clone().iter().enumerate() {
+                let mut rules = self.rules.iter()
+                    .filter(|rule| rule.right == *page && update.pages[index..].contains(&rule.left));
+
+                if let Some(insert) = rules.next().and_then(|rule| update.pages.iter().position(|page| *page == rule.left)) {
+                    let new = update.pages.remove(index);
+
+                    trace!("[sort] new={:?}, insert={:?}", new, insert);
+
+                    update.pages.insert(insert, new);
+
+                    break;
+                }
+            }
+        }
+
+        trace!("[sort] done update={:?}", update);
+    }
+
+    pub fn validate(&self, update: &Update) -> bool {
+        update.pages.iter()
+            .enumerate()
+            .all(|(index, page)| {
+                !self.rules.iter()
+                    .any(|rule| rule.right == *page && update.pages[index..].contains(&rule.left))
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pages: Vec<usize>,
+}
+
+impl Update {
+    pub fn new(pages: Vec<usize>) -> Update {
+        Update {
+            pages,
+        }
+    }
+
+    pub fn parse(input: &str, line: &str) -> Result<Update, ParseError> {
+        Ok(Update::new(aoc::parse::separated::<usize>(input, line, ',', "page")?))
+    }
+
+    pub fn middle(&self) -> usize {
+        self.pages[self.pages.len() / 2]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Part1 {
+    rules: RuleSet,
+    updates: Vec<Update>,
+}
+
+impl Part1 {
+    pub fn calculate(&self) -> usize {
+        self.updates.iter()
+            .fold(0, |mut acc, update| {
+                trace!("[calculate] update={:?}", update);
+
+                if self.rules.validate(update) {
+                    trace!("[calculate] valid");
+
+                    acc += update.middle()
+                }
+
+                acc
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct Part2 {
+    rules: RuleSet,
+    updates: Vec<Update>,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        let updates = part1.updates.into_iter()
+            .filter(|update| !part1.rules.validate(update))
+            .collect::<Vec<Update>>();
+
+        Part2 {
+            rules: part1.rules,
+            updates,
+        }
+    }
+}
+
+impl Part2 {
+    pub fn calculate(&mut self) -> usize {
+        self.updates.iter_mut()
+            .fold(0, |acc, update| { self.rules.sort(update); acc + update.middle() })
+    }
+}
+
+/// Rules between every pair of `size` two digit pages and `size` updates of up to 23 of them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages = (10..100).collect::<Vec<usize>>();
+
+    rng.shuffle(&mut pages);
+    pages.truncate(size.clamp(2, pages.len()));
+
+    // the shuffled pages are the one true order, so the rules can never form a cycle
+    let mut rules = pages.iter()
+        .enumerate()
+        .flat_map(|(index, left)| pages[index + 1..].iter().map(move |right| format!("{}|{}\n", left, right)))
+        .collect::<Vec<String>>();
+
+    rng.shuffle(&mut rules);
+
+    let mut text = rules.concat();
+
+    text.push('\n');
+
+    for _ in 0..size.max(1) {
+        let length = rng.below((pages.len().min(23) as u64 - 1) / 2 + 1) as usize * 2 + 1;
+        let mut update = pages.clone();
+
+        rng.shuffle(&mut update);
+        update.truncate(length);
+
+        if rng.chance(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|other| other == page));
+        }
+
+        text.push_str(&update.iter().map(|page| page.to_string()).collect::<Vec<String>>().join(","));
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Puts every update in order by counting, for each page, the pages of the update that the rules put in front of it.
+///
+/// Only updates with a rule for every pair of their pages and no cycles have a single right order, anything else is rejected.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let (rules, updates) = input.split_once("\n\n").ok_or("expected rules and updates")?;
+
+    let rules = rules.lines()
+        .map(|line| {
+            let (left, right) = line.split_once('|').ok_or("expected a rule")?;
+
+            Ok((left.parse::<usize>()?, right.parse::<usize>()?))
+        })
+        .collect::<aoc::Result<Vec<(usize, usize)>>>()?;
+
+    let mut ordered = 0;
+    let mut reordered = 0;
+
+    for line in updates.lines().filter(|line| !line.trim().is_empty()) {
+        let update = line.split(',').map(|page| page.parse::<usize>()).collect::<Result<Vec<usize>, _>>()?;
+        let before = |page: usize| update.iter().filter(|other| rules.contains(&(**other, page))).count();
+
+        let mut sorted = update.clone();
+
+        sorted.sort_by_key(|page| before(*page));
+
+        if sorted.iter().enumerate().any(|(index, page)| before(*page) != index) {
+            return Err(format!("the rules don't order '{}'", line).into());
+        }
+
+        if sorted == update {
+            ordered += sorted[sorted.len() / 2];
+        } else {
+            reordered += sorted[sorted.len() / 2];
+        }
+    }
+
+    Ok([ordered.into(), reordered.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let mut sections = aoc::parse::sections(input).into_iter();
+
+        let rules = aoc::parse::lines(sections.next().unwrap_or(""))
+            .map(|line| Rule::parse(input, line))
+            .collect::<Result<Vec<Rule>, ParseError>>()?;
+
+        let updates = sections.flat_map(aoc::parse::lines)
+            .map(|line| Update::parse(input, line))
+            .collect::<Result<Vec<Update>, ParseError>>()?;
+
+        Ok(Part1 {
+            rules: RuleSet::new(rules),
+            updates,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_rule() {
+        let err = Part1::parse("47|53\n97-13\n\n75,47\n").err().unwrap().to_string();
+
+        assert_eq!(err, "2:1: expected a rule like 47|53 '97-13'");
+    }
+
+    #[test]
+    fn malformed_update() {
+        let err = Part1::parse("47|53\n\n75,47\n61,,53\n").err().unwrap().to_string();
+
+        assert_eq!(err, "4:4: invalid page");
+    }
+
+    #[test]
+    fn crlf() {
+        let input = include_str!("../test.txt");
+        let crlf = Part1::parse(&input.replace('\n', "\r\n")).unwrap();
+        let lf = Part1::parse(input).unwrap();
+
+        assert_eq!((crlf.part1().unwrap(), crlf.part2().unwrap()), (lf.part1().unwrap(), lf.part2().unwrap()));
+        assert_eq!(Part1::parse("47|5x\r\n\r\n75,47\r\n").err().unwrap().to_string(), "1:4: invalid page '5x'");
+    }
+
+    #[test]
+    fn missing_separator() {
+        let err = Part1::parse("47|53\n75,47,61\n").err().unwrap().to_string();
+
+        assert_eq!(err, "2:1: expected a rule like 47|53 '75,47,61'");
+    }
+}
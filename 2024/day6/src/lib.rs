@@ -0,0 +1,341 @@
+use std::collections::HashSet;
+
+use aoc::animate::{Animation, Cell, Colour, Frame};
+use aoc::image::{Image, Rgb};
+use aoc::random::Rng;
+use aoc::{cycle, debug, trace, Answer, Direction, Grid, ParseError, Point, Solution};
+
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Guard {
+    direction: Direction,
+    position: Point,
+}
+
+impl Guard {
+    pub fn new(x: usize, y: usize, direction: Direction) -> Guard {
+        Guard {
+            direction,
+            position: Point::from_grid(x, y),
+        }
+    }
+
+    /// The cell in front of the guard, `None` once the next step leaves the map.
+    pub fn ahead(&self, map: &Grid<State>) -> Option<Point> {
+        map.step(self.position, self.direction)
+    }
+
+    /// Either turns or walks on, `None` once the guard leaves the map.
+    pub fn patrol(&self, map: &Grid<State>) -> Option<Guard> {
+        let ahead = self.ahead(map)?;
+
+        Some(match map[ahead] {
+            State::Obstructed => Guard { direction: self.direction.rotate_cw(), ..*self },
+            State::Clear => Guard { position: ahead, ..*self },
+        })
+    }
+
+    fn glyph(&self) -> char {
+        match self.direction {
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            _ => '^',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Obstructed,
+    Clear,
+}
+
+impl State {
+    pub fn parse(input: &str, cell: &str) -> Result<State, ParseError> {
+        match cell {
+            "#" => Ok(State::Obstructed),
+            "." | "^" => Ok(State::Clear),
+            _ => Err(ParseError::at(input, cell, "expected '.', '#' or '^'")),
+        }
+    }
+}
+
+impl From<State> for char {
+    fn from(state: State) -> char {
+        match state {
+            State::Obstructed => '#',
+            State::Clear => '.',
+        }
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+#[derive(Clone)]
+pub struct Part1 {
+    grid: Grid<State>,
+    entry: Guard,
+}
+
+impl Part1 {
+    /// Every position the guard walks through before leaving the map.
+    pub fn visited(&self) -> HashSet<Point> {
+        let mut positions = HashSet::from([self.entry.position]);
+        let mut guard = self.entry;
+
+        while let Some(next) = guard.patrol(&self.grid) {
+            guard = next;
+
+            positions.insert(guard.position);
+        }
+
+        positions
+    }
+
+    pub fn simulate(&self) -> usize {
+        self.visited().len()
+    }
+}
+
+/// The guard walking the map one step per frame, with every cell it has visited highlighted.
+pub struct Walk<'a> {
+    grid: &'a Grid<State>,
+    guard: Option<Guard>,
+    visited: HashSet<Point>,
+    steps: usize,
+}
+
+impl Animation for Walk<'_> {
+    fn frame(&mut self) -> Option<Frame> {
+        let guard = self.guard?;
+
+        self.visited.insert(guard.position);
+
+        let frame = Frame::new(self.grid, |state| match state {
+            State::Obstructed => Cell::new('#', Colour::White),
+            State::Clear => Cell::new('.', Colour::Grey),
+        });
+
+        let frame = frame.paint(self.visited.iter().copied(), Colour::Yellow)
+            .mark(guard.position, Cell::new(guard.glyph(), Colour::Red))
+            .caption(format!("step {}, {} cells visited", self.steps, self.visited.len()));
+
+        self.guard = guard.patrol(self.grid);
+        self.steps += 1;
+
+        Some(frame)
+    }
+}
+
+pub struct Part2 {
+    grid: Grid<State>,
+    entry: Guard,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            grid: part1.grid,
+            entry: part1.entry,
+        }
+    }
+}
+
+impl Part2 {
+    pub fn simulate(&self, map: Grid<State>) -> bool {
+        let cycle = cycle::brent(self.entry, |guard| guard.patrol(&map));
+
+        if let Some(cycle) = cycle {
+            debug!("[simulate] guard loops after {} steps, every {} steps", cycle.start, cycle.length);
+        }
+
+        cycle.is_some()
+    }
+
+    pub fn calculate(&self) -> usize {
+        let mut count = 0;
+
+        for position in self.grid.points().filter(|position| *position != self.entry.position) {
+            let mut map = self.grid.clone();
+
+            map[position] = State::Obstructed;
+
+            trace!("[calculate] simulating x={}, y={}", position.x, position.y);
+
+            if self.simulate(map) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+/// A `size` by `size` map with about one cell in twenty obstructed and the guard on a clear cell.
+///
+/// Maps the guard never leaves are thrown away, the puzzle has no answer for them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+
+    loop {
+        let mut cells = (0..size * size).map(|_| if rng.chance(0.05) { '#' } else { '.' }).collect::<Vec<char>>();
+        let start = rng.below(cells.len() as u64) as usize;
+
+        cells[start] = '^';
+
+        let map = Grid::new(size, size, cells.iter().map(|c| if *c == '#' { State::Obstructed } else { State::Clear }).collect());
+        let guard = Guard::new(start % size, start / size, Direction::Up);
+
+        if cycle::brent(guard, |guard| guard.patrol(&map)).is_none() {
+            return cells.chunks(size)
+                .map(|row| row.iter().chain(['\n'].iter()).collect::<String>())
+                .collect();
+        }
+    }
+}
+
+/// Walks the guard one cell at a time and spots loops by a repeated position and heading, part 2 tries an obstacle on every cell.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let rows = input.lines().filter(|line| !line.trim().is_empty()).map(|line| line.chars().collect::<Vec<char>>()).collect::<Vec<Vec<char>>>();
+
+    if rows.is_empty() || rows.iter().any(|row| row.len() != rows[0].len() || row.iter().any(|c| !".#^".contains(*c))) {
+        return Err("expected a rectangle of '.', '#' and '^'".into());
+    }
+
+    let guards = rows.iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, c)| **c == '^').map(move |(x, _)| (x as i64, y as i64)))
+        .collect::<Vec<(i64, i64)>>();
+
+    let [start] = guards[..] else {
+        return Err("expected exactly one guard".into());
+    };
+
+    // the cells the guard visits, `None` if it never leaves
+    let walk = |obstacle: Option<(i64, i64)>| -> Option<HashSet<(i64, i64)>> {
+        let at = |x: i64, y: i64| -> Option<char> {
+            if Some((x, y)) == obstacle {
+                return Some('#');
+            }
+
+            rows.get(usize::try_from(y).ok()?)?.get(usize::try_from(x).ok()?).copied()
+        };
+
+        let ((mut x, mut y), (mut dx, mut dy)) = (start, (0, -1));
+        let mut seen = HashSet::new();
+
+        while seen.insert((x, y, dx, dy)) {
+            match at(x + dx, y + dy) {
+                None => return Some(seen.iter().map(|(x, y, _, _)| (*x, *y)).collect()),
+                Some('#') => (dx, dy) = (-dy, dx),
+                Some(_) => (x, y) = (x + dx, y + dy),
+            }
+        }
+
+        None
+    };
+
+    let visited = walk(None).ok_or("the guard never leaves")?;
+
+    let loops = (0..rows.len() as i64)
+        .flat_map(|y| (0..rows[0].len() as i64).map(move |x| (x, y)))
+        .filter(|cell| *cell != start && walk(Some(*cell)).is_none())
+        .count();
+
+    Ok([visited.len().into(), loops.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let grid = Grid::parse(input, |cell| State::parse(input, cell))?;
+        let rows = aoc::input::rows(input)?;
+
+        // every cell is ascii by now, so byte offsets are columns
+        let mut guards = rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.match_indices('^').map(move |(x, token)| (x, y, token)));
+
+        let entry = guards.next()
+            .map(|(x, y, _)| Guard::new(x, y, Direction::Up))
+            .ok_or_else(|| ParseError::eof(input, "missing guard '^'"))?;
+
+        if let Some((_, _, token)) = guards.next() {
+            return Err(ParseError::at(input, token, "more than one guard").into());
+        }
+
+        Ok(Part1 {
+            grid,
+            entry,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.simulate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+
+    fn animation(&self) -> Option<Box<dyn Animation + '_>> {
+        Some(Box::new(Walk {
+            grid: &self.grid,
+            guard: Some(self.entry),
+            visited: HashSet::new(),
+            steps: 0,
+        }))
+    }
+
+    fn image(&self) -> Option<Image> {
+        let visited = self.visited();
+        let mut map = self.grid.map(|state| match state {
+            State::Obstructed => Rgb(200, 200, 200),
+            State::Clear => Rgb(20, 20, 30),
+        });
+
+        for position in visited {
+            map[position] = Rgb(240, 200, 40);
+        }
+
+        map[self.entry.position] = Rgb(220, 50, 47);
+
+        Some(Image::new(&map, 4, |pixel| *pixel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Part1::parse("..#\n.^x\n").err().unwrap().to_string(), "2:3: expected '.', '#' or '^' 'x'");
+        assert_eq!(Part1::parse("..#\n...\n").err().unwrap().to_string(), "3:1: missing guard '^' at end of input");
+        assert_eq!(Part1::parse("..^\n.^.\n").err().unwrap().to_string(), "2:2: more than one guard '^'");
+    }
+
+    #[test]
+    fn narrow_grid() {
+        let part1 = Part1::parse(".#\n..\n..\n^.\n").unwrap();
+
+        assert_eq!(part1.part1().unwrap().to_string(), "4");
+        assert_eq!(part1.part2().unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn walk() {
+        let part1 = Part1::parse(".#\n..\n..\n^.\n").unwrap();
+        let mut walk = part1.animation().unwrap();
+
+        assert_eq!(walk.frame().unwrap().render(false), ".#\n..\n..\n^.\nstep 0, 1 cells visited\n");
+
+        let last = std::iter::from_fn(|| walk.frame()).last().unwrap();
+
+        assert_eq!(last.render(false), "^#\n..\n..\n..\nstep 3, 4 cells visited\n");
+    }
+}
@@ -0,0 +1,180 @@
+use aoc::random::Rng;
+use aoc::{debug, Answer, ParseError, Solution};
+
+
+#[derive(Debug)]
+pub struct Equation {
+    product: u64,
+    numbers: Vec<u64>,
+}
+
+impl Equation {
+    pub fn parse(input: &str, line: &str) -> Result<Equation, ParseError> {
+        let (product, numbers) = line.split_once(':')
+            .ok_or_else(|| ParseError::at(input, line, "expected an equation like 190: 10 19"))?;
+
+        let product = aoc::error::parse::<u64>(input, product.trim(), "test value")?;
+
+        // zero has no digits to concatenate and can't be divided by
+        let values = aoc::parse::numbers::<u64>(input, numbers, "number")?;
+
+        if let Some((zero, _)) = numbers.split_ascii_whitespace().zip(values.iter()).find(|(_, value)| **value == 0) {
+            return Err(ParseError::at(input, zero, "numbers have to be positive"));
+        }
+
+        if values.is_empty() {
+            return Err(ParseError::at(input, line, "equation has no numbers"));
+        }
+
+        Ok(Equation {
+            product,
+            numbers: values,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Part1 {
+    equations: Vec<Equation>,
+}
+
+impl Part1 {
+    fn solve(&self, goal: u64, numbers: &[u64], concat: bool) -> bool {
+        match numbers {
+            // the first number is never combined with anything, it has to be all that is left
+            [first] => goal == *first,
+            [rest @ .., number] => {
+                let tens = 10u64.pow(number.ilog10() + 1);
+
+                goal.checked_sub(*number).map(|result| self.solve(result, rest, concat)).unwrap_or(false)
+                    || goal.is_multiple_of(*number) && self.solve(goal / number, rest, concat)
+                    || concat && goal.checked_sub(*number).map(|result| result % tens == 0 && self.solve(result / tens, rest, concat)).unwrap_or(false)
+            },
+            [] => false,
+        }
+    }
+
+    fn valid(&self, equation: &Equation, concat: bool) -> bool {
+        self.solve(equation.product, &equation.numbers, concat)
+    }
+
+    pub fn calculate(&self, concat: bool) -> u64 {
+        debug!("[calculate] equations={}", self.equations.len());
+
+        self.equations.iter()
+            .filter(|equation| self.valid(equation, concat))
+            .fold(0, |acc, equation| acc + equation.product)
+    }
+}
+
+/// `size` equations of two to five numbers, a third can be made true, a third is just out of reach
+/// and the rest have small test values that have nothing to do with the numbers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+
+    for _ in 0..size.max(1) {
+        let numbers = (0..rng.between(2, 5)).map(|_| rng.between(1, 99)).collect::<Vec<u64>>();
+        let mut value = numbers[0];
+
+        for number in numbers[1..].iter() {
+            value = match rng.below(3) {
+                0 => value + number,
+                1 => value * number,
+                _ => value * 10u64.pow(number.ilog10() + 1) + number,
+            };
+        }
+
+        match rng.below(3) {
+            0 => {},
+            // usually out of reach after this
+            1 => value += rng.between(1, 10),
+            _ => value = rng.between(1, 50),
+        }
+
+        text.push_str(&format!("{}: {}\n", value, numbers.iter().map(|number| number.to_string()).collect::<Vec<String>>().join(" ")));
+    }
+
+    text
+}
+
+/// Tries every combination of operators, evaluated left to right.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let mut totals = [0u64; 2];
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (target, numbers) = line.split_once(':').ok_or("expected an equation")?;
+        let target = target.trim().parse::<u64>()?;
+        let numbers = numbers.split_whitespace().map(|number| number.parse::<u64>()).collect::<Result<Vec<u64>, _>>()?;
+
+        if numbers.is_empty() || numbers.contains(&0) {
+            return Err("expected positive numbers".into());
+        }
+
+        for (total, operators) in totals.iter_mut().zip([2u64, 3]) {
+            let reachable = (0..operators.pow(numbers.len() as u32 - 1)).any(|mut combination| {
+                let value = numbers[1..].iter().try_fold(numbers[0] as u128, |value, number| {
+                    let number = *number as u128;
+                    let operator = combination % operators;
+
+                    combination /= operators;
+
+                    match operator {
+                        0 => value.checked_add(number),
+                        1 => value.checked_mul(number),
+                        _ => value.checked_mul(10u128.pow(number.ilog10() + 1))?.checked_add(number),
+                    }
+                });
+
+                value == Some(target as u128)
+            });
+
+            if reachable {
+                *total += target;
+            }
+        }
+    }
+
+    Ok([totals[0].into(), totals[1].into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let equations = aoc::parse::lines(input)
+            .map(|line| Equation::parse(input, line))
+            .collect::<Result<Vec<Equation>, ParseError>>()?;
+
+        Ok(Part1 {
+            equations,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate(false).into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate(true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Part1::parse("190: 10 19\n3267 81 40\n").err().unwrap().to_string(), "2:1: expected an equation like 190: 10 19 '3267 81 40'");
+        assert_eq!(Part1::parse("190: 10 1x9\n").err().unwrap().to_string(), "1:9: invalid number '1x9'");
+        assert_eq!(Part1::parse("190: 10 0\n").err().unwrap().to_string(), "1:9: numbers have to be positive '0'");
+        assert_eq!(Part1::parse("190:\n").err().unwrap().to_string(), "1:1: equation has no numbers '190:'");
+    }
+
+    #[test]
+    fn leftover_first_number() {
+        // peeling off 2 and 2 leaves exactly 0, but the 7 still has to make up the rest
+        let part1 = Part1::parse("4: 7 2 2\n").unwrap();
+
+        assert_eq!((part1.calculate(false), part1.calculate(true)), (0, 0));
+        assert_eq!(reference("4: 7 2 2\n").unwrap(), [0u64.into(), 0u64.into()]);
+    }
+}
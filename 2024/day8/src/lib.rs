@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::image::{Image, Rgb};
+use aoc::animate::Colour;
+use aoc::random::Rng;
+use aoc::{Answer, Grid, ParseError, Point, Solution, Vector};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Antenna {
+    freq: char,
+    pos: Point,
+}
+
+impl Antenna {
+    pub fn new(freq: char, pos: Point) -> Antenna {
+        Antenna {
+            freq,
+            pos,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Part1 {
+    antennas: Vec<Antenna>,
+    grid: Grid<char>,
+}
+
+impl Part1 {
+    pub fn antinodes(&self) -> HashSet<Point> {
+        let mut positions = HashSet::new();
+
+        for a in self.antennas.iter() {
+            for b in self.antennas.iter().filter(|antenna| antenna.freq == a.freq && antenna.pos != a.pos) {
+                let diff = a.pos - b.pos;
+
+                for anti in [b.pos - diff, a.pos + diff] {
+                    if self.grid.contains(anti) {
+                        positions.insert(anti);
+                    }
+                }
+
+                // a third of the way from one antenna to the other, the far one is twice as far away
+                if diff.x % 3 == 0 && diff.y % 3 == 0 {
+                    positions.insert(b.pos + Vector::new(diff.x / 3, diff.y / 3));
+                }
+            }
+        }
+
+        positions
+    }
+
+    pub fn calculate(&self) -> usize {
+        self.antinodes().len()
+    }
+}
+
+pub struct Part2 {
+    antennas: Vec<Antenna>,
+    grid: Grid<char>,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            antennas: part1.antennas,
+            grid: part1.grid,
+        }
+    }
+}
+
+impl Part2 {
+    pub fn calculate(&self) -> usize {
+        let mut positions: HashMap<Point, ()> = HashMap::new();
+
+        for a in self.antennas.iter() {
+            for b in self.antennas.iter().filter(|antenna| antenna.freq == a.freq && antenna.pos != a.pos) {
+                let diff = a.pos - b.pos;
+                let gcd = gcd(diff.x.abs(), diff.y.abs());
+                let step = Vector::new(diff.x / gcd, diff.y / gcd);
+
+                // every grid point on the line through both antennas, in both directions
+                for (start, step) in [(b.pos, step), (b.pos, -step)] {
+                    for anti in self.grid.ray(start, step) {
+                        positions.insert(anti, ());
+                    }
+                }
+            }
+        }
+
+        positions.len()
+    }
+}
+
+/// A `size` by `size` map with an antenna in about one cell in twenty, more frequencies on larger maps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let frequencies = &FREQUENCIES[..(size / 4).clamp(1, FREQUENCIES.len())];
+
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.05) { *rng.pick(frequencies) as char } else { '.' })
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Checks every cell against every pair of antennas with the same frequency, as the puzzle words it.
+///
+/// A cell is an antinode for part 1 when it is in line with both antennas and one is twice as far away as
+/// the other, and for part 2 when it is in line with both at all.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let rows = input.lines().filter(|line| !line.trim().is_empty()).map(|line| line.chars().collect::<Vec<char>>()).collect::<Vec<Vec<char>>>();
+
+    if rows.is_empty() || rows.iter().any(|row| row.len() != rows[0].len() || row.iter().any(|c| *c != '.' && !c.is_ascii_alphanumeric())) {
+        return Err("expected a rectangle of '.' and antennas".into());
+    }
+
+    let cells = (0..rows.len() as i64).flat_map(|y| (0..rows[0].len() as i64).map(move |x| (x, y))).collect::<Vec<(i64, i64)>>();
+    let antennas = cells.iter().copied().filter(|(x, y)| rows[*y as usize][*x as usize] != '.').collect::<Vec<(i64, i64)>>();
+    let frequency = |(x, y): (i64, i64)| rows[y as usize][x as usize];
+
+    let pairs = antennas.iter()
+        .flat_map(|a| antennas.iter().map(move |b| (*a, *b)))
+        .filter(|(a, b)| a != b && frequency(*a) == frequency(*b))
+        .collect::<Vec<((i64, i64), (i64, i64))>>();
+
+    let in_line = |(x, y): (i64, i64), (a, b): ((i64, i64), (i64, i64))| (x - a.0) * (b.1 - a.1) == (y - a.1) * (b.0 - a.0);
+    let distance = |(x, y): (i64, i64), (ax, ay): (i64, i64)| (x - ax).pow(2) + (y - ay).pow(2);
+
+    let twice = cells.iter()
+        .filter(|cell| pairs.iter().any(|pair| in_line(**cell, *pair) && distance(**cell, pair.0) == 4 * distance(**cell, pair.1)))
+        .count();
+
+    let any = cells.iter()
+        .filter(|cell| pairs.iter().any(|pair| in_line(**cell, *pair)))
+        .count();
+
+    Ok([twice.into(), any.into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let grid = Grid::parse(input, |cell| {
+            cell.chars()
+                .next()
+                .filter(|c| *c == '.' || c.is_ascii_alphanumeric())
+                .ok_or_else(|| ParseError::at(input, cell, "expected '.' or an antenna frequency"))
+        })?;
+
+        let antennas = grid.iter()
+            .filter(|(_, freq)| **freq != '.')
+            .map(|(position, freq)| Antenna::new(*freq, position))
+            .collect::<Vec<Antenna>>();
+
+        Ok(Part1 {
+            antennas,
+            grid,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.calculate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+
+    /// Antinodes in white, antennas on top of them coloured by frequency.
+    fn image(&self) -> Option<Image> {
+        let mut map = self.grid.map(|_| Rgb(20, 20, 30));
+
+        for anti in self.antinodes() {
+            map[anti] = Rgb::WHITE;
+        }
+
+        for antenna in self.antennas.iter() {
+            map[antenna.pos] = Colour::PALETTE[antenna.freq as usize % Colour::PALETTE.len()].into();
+        }
+
+        Some(Image::new(&map, 8, |pixel| *pixel))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Part1::parse("..a.\n.#..\n").err().unwrap().to_string(), "2:2: expected '.' or an antenna frequency '#'");
+        assert_eq!(Part1::parse("..a.\n...\n").err().unwrap().to_string(), "2:1: expected a row of width 4 '...'");
+    }
+
+    #[test]
+    fn between_antennas() {
+        let part1 = Part1::parse("a.a\n").unwrap();
+
+        assert_eq!((part1.part1().unwrap().to_string(), part1.part2().unwrap().to_string()), ("0".into(), "3".into()));
+
+        let part1 = Part1::parse("a..a\n").unwrap();
+
+        assert_eq!((part1.part1().unwrap().to_string(), part1.part2().unwrap().to_string()), ("2".into(), "4".into()));
+    }
+}
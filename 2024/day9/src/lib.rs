@@ -0,0 +1,384 @@
+use aoc::animate::{Animation, Cell, Colour, Frame};
+use aoc::random::Rng;
+use aoc::{trace, Answer, Grid, ParseError, Point, Solution};
+
+const WIDTH: usize = 64;
+
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Block {
+    File {
+        id: usize,
+    },
+    Free,
+}
+
+impl Block {
+    pub fn is_free(&self) -> bool {
+        *self == Block::Free
+    }
+
+    pub fn is_file(&self) -> bool {
+        match self {
+            Block::File { .. } => true,
+            Block::Free => false,
+        }
+    }
+
+    pub fn character(&self) -> char {
+        match self {
+            Block::File { id } => char::from_digit(*id as u32, 10).unwrap_or('.'),
+            Block::Free => '.',
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        match self {
+            Block::File { id } => *id,
+            Block::Free => 0,
+        }
+    }
+
+    fn cell(&self) -> Cell {
+        match self {
+            Block::File { id } => Cell::new(char::from_digit((*id % 10) as u32, 10).unwrap_or('?'), Colour::PALETTE[*id % Colour::PALETTE.len()]),
+            Block::Free => Cell::new('.', Colour::Grey),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Group {
+    block: Block,
+    size: usize,
+}
+
+impl Group {
+    pub fn new(block: Block, size: usize) -> Group {
+        Group {
+            block,
+            size,
+        }
+    }
+
+    pub fn map(&self) -> Vec<Block> {
+        vec![self.block.clone(); self.size]
+    }
+}
+
+trait Partition {
+    fn sort(&mut self);
+
+    fn map(&self) -> Vec<Block>;
+
+    fn calculate(&mut self) -> usize {
+        self.sort();
+
+        self.map()
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (index, block)| {
+                match block {
+                    Block::File { id } => acc + (*id * index),
+                    Block::Free => acc,
+                }
+            })
+    }
+}
+
+#[derive(Clone)]
+pub struct Part1 {
+    map: Vec<Block>,
+    groups: Vec<Group>,
+}
+
+impl Part1 {
+    fn unsorted(&self, lim: usize) -> Option<usize> {
+        self.map[..lim].iter()
+            .rposition(|block| block.is_file())
+    }
+
+    fn free(&self, offset: usize) -> Option<usize> {
+        self.map[offset..].iter()
+            .position(|block| block.is_free())
+            .map(|index| index + offset)
+    }
+
+    /// Moves the last file block into the first free block between `offset` and `lim`, and returns both indices.
+    fn compact(&mut self, offset: usize, lim: usize) -> Option<(usize, usize)> {
+        let (free, unsorted) = self.free(offset).zip(self.unsorted(lim)).filter(|(free, unsorted)| free < unsorted)?;
+
+        trace!("[sort] unsorted={}, free={}", unsorted, free);
+
+        self.map.swap(free, unsorted);
+
+        Some((free, unsorted))
+    }
+}
+
+impl Partition for Part1 {
+    fn sort(&mut self) {
+        let mut offset = 0;
+        let mut lim = self.map.len();
+
+        // both ends only ever move inwards, so we never rescan the part that is already sorted
+        while let Some((free, unsorted)) = self.compact(offset, lim) {
+            offset = free + 1;
+            lim = unsorted;
+        }
+    }
+
+    fn map(&self) -> Vec<Block> {
+        self.map.clone()
+    }
+}
+
+/// Part 1 compacting the disk one block per frame, the disk wraps every `WIDTH` blocks.
+pub struct Compaction {
+    part1: Part1,
+    offset: usize,
+    lim: usize,
+    moved: Option<(usize, usize)>,
+    done: bool,
+}
+
+impl Compaction {
+    fn draw(&self) -> Frame {
+        let height = self.part1.map.len().div_ceil(WIDTH);
+
+        let cells = (0..WIDTH * height)
+            .map(|index| self.part1.map.get(index).map(|block| block.cell()).unwrap_or(Cell::new(' ', Colour::Default)))
+            .collect::<Vec<Cell>>();
+
+        let moved = self.moved.into_iter()
+            .flat_map(|(free, unsorted)| [free, unsorted])
+            .map(|index| Point::from_grid(index % WIDTH, index / WIDTH));
+
+        Frame::new(&Grid::new(WIDTH, height, cells), |cell| *cell)
+            .paint(moved, Colour::White)
+            .caption(format!("{} blocks left to check", self.lim.saturating_sub(self.offset)))
+    }
+}
+
+impl Animation for Compaction {
+    fn frame(&mut self) -> Option<Frame> {
+        if self.done {
+            return None;
+        }
+
+        let frame = self.draw();
+
+        match self.part1.compact(self.offset, self.lim) {
+            Some((free, unsorted)) => {
+                self.offset = free + 1;
+                self.lim = unsorted;
+                self.moved = Some((free, unsorted));
+            },
+            None => self.done = true,
+        }
+
+        Some(frame)
+    }
+}
+
+#[derive(Clone)]
+pub struct Part2 {
+    groups: Vec<Group>,
+}
+
+impl From<Part1> for Part2 {
+    fn from(part1: Part1) -> Part2 {
+        Part2 {
+            groups: part1.groups,
+        }
+    }
+}
+
+impl Part2 {
+    fn unsorted(&self, lim: usize) -> Option<usize> {
+        self.groups[..=lim].iter()
+            .enumerate()
+            .rev()
+            .find(|(_, group)| group.block.is_file())
+            .map(|(index, _)| index)
+    }
+
+    fn free(&self, size: usize, unsorted: usize) -> Option<usize> {
+        self.groups[..unsorted].iter()
+            .enumerate()
+            .find(|(_, group)| group.block.is_free() && group.size >= size)
+            .map(|(index, _)| index)
+    }
+}
+
+impl Partition for Part2 {
+    fn sort(&mut self) {
+        let mut lim = self.groups.len() - 1;
+
+        while let Some(unsorted) = self.unsorted(lim) {
+            match self.free(self.groups[unsorted].size, unsorted) {
+                Some(free) => {
+                    trace!("free: {:?}, free_idx: {}, unsorted: {:?}", self.groups[free], free, self.groups[unsorted]);
+
+                    self.groups.swap(free, unsorted);
+
+                    let free_group = self.groups[unsorted].clone();
+                    let unsorted_group = self.groups[free].clone();
+
+                    // this is actually checking free > unsorted, but we swapped it
+                    if free_group.size > unsorted_group.size {
+                        self.groups.insert(free + 1, Group::new(Block::Free, free_group.size - unsorted_group.size));
+
+                        self.groups[unsorted + 1].size -= free_group.size - unsorted_group.size;
+                    }
+
+                    // everything right of unsorted has already had its chance to move
+                    lim = unsorted;
+                },
+                None => {
+                    match unsorted.checked_sub(1) {
+                        Some(next) => lim = next,
+                        None => break,
+                    }
+                },
+            }
+        }
+    }
+
+    fn map(&self) -> Vec<Block> {
+        self.groups.iter()
+            .cloned()
+            .flat_map(|group| group.map())
+            .collect::<Vec<Block>>()
+    }
+}
+
+/// A disk map of `size` digits, files of one to nine blocks with up to nine free blocks after each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|index| if index % 2 == 0 { rng.between(1, 9) } else { rng.between(0, 9) })
+        .map(|digit| char::from_digit(digit as u32, 10).unwrap_or('0'))
+        .chain(['\n'])
+        .collect()
+}
+
+/// Compacts a plain list of blocks, one block at a time for part 1 and whole files for part 2.
+pub fn reference(input: &str) -> aoc::Result<[Answer; 2]> {
+    let digits = input.trim_end().chars().map(|c| c.to_digit(10).ok_or("expected a digit")).collect::<Result<Vec<u32>, _>>()?;
+
+    if digits.is_empty() {
+        return Err("expected a disk map".into());
+    }
+
+    let disk = digits.iter()
+        .enumerate()
+        .flat_map(|(index, digit)| std::iter::repeat_n((index % 2 == 0).then_some(index / 2), *digit as usize))
+        .collect::<Vec<Option<usize>>>();
+
+    let checksum = |blocks: &[Option<usize>]| blocks.iter().enumerate().map(|(index, id)| index * id.unwrap_or(0)).sum::<usize>();
+
+    let mut blocks = disk.clone();
+
+    while let (Some(free), Some(last)) = (blocks.iter().position(Option::is_none), blocks.iter().rposition(Option::is_some)) {
+        if free > last {
+            break;
+        }
+
+        blocks.swap(free, last);
+    }
+
+    let mut files = disk;
+
+    for id in (0..digits.len().div_ceil(2)).rev() {
+        let Some(start) = files.iter().position(|block| *block == Some(id)) else {
+            continue;
+        };
+
+        let length = files.iter().filter(|block| **block == Some(id)).count();
+        let target = (0..start).find(|free| files[*free..*free + length].iter().all(Option::is_none));
+
+        if let Some(target) = target {
+            for offset in 0..length {
+                files.swap(target + offset, start + offset);
+            }
+        }
+    }
+
+    Ok([checksum(&blocks).into(), checksum(&files).into()])
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        let digits = aoc::parse::digits(input, "digit")?;
+
+        if digits.is_empty() {
+            return Err(ParseError::eof(input, "expected a disk map").into());
+        }
+
+        let groups = digits
+            .into_iter()
+            .enumerate()
+            .map(|(index, size)| {
+                if index % 2 != 0 {
+                    Group::new(Block::Free, size as usize)
+                } else {
+                    Group::new(Block::File { id: index / 2 }, size as usize)
+                }
+            })
+            .collect::<Vec<Group>>();
+
+        Ok(Part1 {
+            map: groups.iter().flat_map(|group| group.map()).collect::<Vec<Block>>(),
+            groups,
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Ok(self.clone().calculate().into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Ok(Part2::from(self.clone()).calculate().into())
+    }
+
+    fn animation(&self) -> Option<Box<dyn Animation + '_>> {
+        Some(Box::new(Compaction {
+            part1: self.clone(),
+            offset: 0,
+            lim: self.map.len(),
+            moved: None,
+            done: false,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Part1::parse("2333133121414131402\n").unwrap().part1().unwrap().to_string(), "1928");
+        assert_eq!(Part1::parse("23331 33121\n").err().unwrap().to_string(), "1:6: expected a digit ' '");
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Part1::parse("").err().unwrap().to_string(), "1:1: expected a disk map at end of input");
+        assert_eq!(Part1::parse("\n").err().unwrap().to_string(), "2:1: expected a disk map at end of input");
+    }
+
+    #[test]
+    fn compaction() {
+        let part1 = Part1::parse("12345\n").unwrap();
+        let frames = std::iter::from_fn({
+            let mut animation = part1.animation().unwrap();
+
+            move || animation.frame()
+        }).collect::<Vec<Frame>>();
+
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0].render(false).lines().next().unwrap().trim_end(), "0..111....22222");
+        assert_eq!(frames[5].render(false).lines().next().unwrap().trim_end(), "022111222......");
+    }
+}
@@ -0,0 +1,47 @@
+use std::io;
+
+use aoc::animate::{self, Player};
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+const FPS: f64 = 10.0;
+
+
+pub fn animate(args: &Args) -> aoc::Result<()> {
+    let (year, day) = match args.selection()? {
+        (Some(year), Some(day)) => (year, day),
+        _ => return Err("animate needs a year and a day".into()),
+    };
+
+    // stdin is where the controls come from
+    if args.option("input") == Some("-") {
+        return Err("animate can't read its input from stdin".into());
+    }
+
+    let day = aoc::registry::select(DAYS, Some(year), Some(day))?[0];
+    let input = input::load(day, args.option("input"))?;
+    let solution = (day.parse)(&input.content)
+        .map_err(|err| aoc::error::with_file(err, &input.name))?;
+
+    let mut animation = solution.animation()
+        .ok_or_else(|| format!("{} day {} has no animation", day.year, day.day))?;
+
+    let fps = args.option_as::<f64>("fps")?.unwrap_or(FPS);
+
+    if fps <= 0.0 {
+        return Err("--fps has to be positive".into());
+    }
+
+    let mut player = Player::new(fps);
+
+    player.paused = args.switch("step");
+    player.ansi = !args.switch("plain");
+
+    let shown = player.play(animation.as_mut(), &mut io::stdout().lock(), &animate::controls())?;
+
+    aoc::debug!("[animate] showed {} frames", shown);
+
+    Ok(())
+}
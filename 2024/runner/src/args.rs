@@ -0,0 +1,100 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::fmt::Display;
+
+const SWITCHES: &[&str] = &["explain", "help", "plain", "record", "step"];
+
+
+#[derive(Debug, Default)]
+pub struct Args {
+    pub command: Option<String>,
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    switches: HashSet<String>,
+}
+
+impl Args {
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> aoc::Result<Args> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) if SWITCHES.contains(&name) => {
+                    parsed.switches.insert(name.to_string());
+                },
+                Some(name) => {
+                    let (name, value) = match name.split_once('=') {
+                        Some((name, value)) => (name.to_string(), value.to_string()),
+                        None => (name.to_string(), args.next().ok_or_else(|| format!("missing value for --{}", name))?),
+                    };
+
+                    parsed.options.insert(name, value);
+                },
+                None if parsed.command.is_none() => parsed.command = Some(arg),
+                None => parsed.positional.push(arg),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn positional<T>(&self, index: usize, name: &str) -> aoc::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.positional.get(index)
+            .map(|value| value.parse::<T>().map_err(|err| format!("invalid {} '{}': {}", name, value, err).into()))
+            .transpose()
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|value| value.as_str())
+    }
+
+    pub fn option_as<T>(&self, name: &str) -> aoc::Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.option(name)
+            .map(|value| value.parse::<T>().map_err(|err| format!("invalid --{} '{}': {}", name, value, err).into()))
+            .transpose()
+    }
+
+    pub fn switch(&self, name: &str) -> bool {
+        self.switches.contains(name)
+    }
+
+    pub fn selection(&self) -> aoc::Result<(Option<u32>, Option<u32>)> {
+        Ok((self.positional::<u32>(0, "year")?, self.positional::<u32>(1, "day")?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Args {
+        Args::parse(args.split_whitespace().map(String::from)).unwrap()
+    }
+
+    #[test]
+    fn options() {
+        let args = parse("run 2024 7 --part 2 --input=test.txt --help");
+
+        assert_eq!(args.command.as_deref(), Some("run"));
+        assert_eq!(args.selection().unwrap(), (Some(2024), Some(7)));
+        assert_eq!(args.option_as::<u32>("part").unwrap(), Some(2));
+        assert_eq!(args.option("input"), Some("test.txt"));
+        assert!(args.switch("help"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Args::parse(["run", "--part"].map(String::from)).is_err());
+        assert!(parse("run 2024 seven").selection().is_err());
+        assert!(parse("run --part x").option_as::<u32>("part").is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::process::Command;
+use std::path::Path;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+
+use aoc::time::{self, Stats};
+use aoc::{Day, Part};
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+pub const HISTORY: &str = "bench.txt";
+
+const ITERATIONS: usize = 10;
+const BUDGET: f64 = 5.0;
+const THRESHOLD: f64 = 10.0;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+/// One line of the history file, durations are stored in nanoseconds.
+#[derive(Debug, PartialEq)]
+struct Record {
+    timestamp: u64,
+    commit: String,
+    year: u32,
+    day: u32,
+    input: String,
+    phase: String,
+    stats: Stats,
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        let nanos = |index: usize| fields.get(index)?.parse::<u64>().ok().map(Duration::from_nanos);
+
+        (fields.len() == 10).then_some(())?;
+
+        Some(Record {
+            timestamp: fields[0].parse().ok()?,
+            commit: fields[1].to_string(),
+            year: fields[2].parse().ok()?,
+            day: fields[3].parse().ok()?,
+            input: fields[4].to_string(),
+            phase: fields[5].to_string(),
+            stats: Stats {
+                iterations: fields[6].parse().ok()?,
+                min: nanos(7)?,
+                median: nanos(8)?,
+                max: nanos(9)?,
+            },
+        })
+    }
+
+    fn matches(&self, other: &Record) -> bool {
+        self.year == other.year && self.day == other.day && self.input == other.input && self.phase == other.phase
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {} {} {} {}",
+            self.timestamp, self.commit, self.year, self.day, self.input, self.phase,
+            self.stats.iterations, self.stats.min.as_nanos(), self.stats.median.as_nanos(), self.stats.max.as_nanos(),
+        )
+    }
+}
+
+/// Runs `f` until either the iterations or the time budget run out, but at least once.
+fn measure<T>(iterations: usize, budget: Duration, mut f: impl FnMut() -> aoc::Result<T>) -> aoc::Result<(Stats, T)> {
+    let mut samples = Vec::with_capacity(iterations);
+    let started = Instant::now();
+
+    loop {
+        let start = Instant::now();
+        let value = f()?;
+
+        samples.push(start.elapsed());
+
+        if samples.len() >= iterations || started.elapsed() >= budget {
+            let stats = Stats::new(samples).ok_or("no samples were taken")?;
+
+            return Ok((stats, value));
+        }
+    }
+}
+
+fn commit(root: &Path) -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(root)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or(String::from("unknown"))
+}
+
+fn bench_day(day: &Day, name: &str, iterations: usize, budget: Duration) -> aoc::Result<Vec<(Phase, Stats)>> {
+    let input = input::load(day, Some(name))?;
+
+    let (parse, solution) = measure(iterations, budget, || {
+        (day.parse)(&input.content).map_err(|err| aoc::error::with_file(err, &input.name))
+    })?;
+
+    let mut phases = vec![(Phase::Parse, parse)];
+
+    for part in Part::ALL {
+        let (stats, _) = measure(iterations, budget, || part.solve(solution.as_ref()))?;
+
+        phases.push((Phase::Part(part), stats));
+    }
+
+    Ok(phases)
+}
+
+pub fn bench(args: &Args) -> aoc::Result<()> {
+    let (year, day) = args.selection()?;
+    let name = args.option("input").unwrap_or(input::DEFAULT);
+    let iterations = args.option_as::<usize>("iterations")?.unwrap_or(ITERATIONS).max(1);
+    let budget = args.option_as::<f64>("budget")?.unwrap_or(BUDGET);
+    let budget = Duration::try_from_secs_f64(budget).map_err(|err| format!("invalid --budget '{}': {}", budget, err))?;
+    let threshold = args.option_as::<f64>("threshold")?.unwrap_or(THRESHOLD);
+
+    let root = input::root();
+    let path = args.option("history").map(Path::new).map(Path::to_path_buf).unwrap_or(root.join(HISTORY));
+
+    let history = match fs::read_to_string(&path) {
+        Ok(history) => history.lines().filter_map(Record::parse).collect::<Vec<Record>>(),
+        Err(_) => Vec::new(),
+    };
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let commit = commit(&root);
+
+    let mut records = Vec::new();
+    let mut regressions = 0;
+
+    println!("{:<14} {:<12} {:<6} {:>6} {:>10} {:>10} {:>10}", "day", "input", "phase", "runs", "min", "median", "max");
+
+    for day in aoc::registry::select(DAYS, year, day)? {
+        for (phase, stats) in bench_day(day, name, iterations, budget)? {
+            let record = Record {
+                timestamp,
+                commit: commit.clone(),
+                year: day.year,
+                day: day.day,
+                input: name.to_string(),
+                phase: phase.to_string(),
+                stats,
+            };
+
+            let mut line = format!(
+                "{:<14} {:<12} {:<6} {:>6} {:>10} {:>10} {:>10}",
+                format!("{} day {}", day.year, day.day), name, phase, stats.iterations,
+                time::format(stats.min), time::format(stats.median), time::format(stats.max),
+            );
+
+            if let Some(previous) = history.iter().rev().find(|previous| previous.matches(&record)) {
+                let change = (stats.median.as_secs_f64() / previous.stats.median.as_secs_f64().max(f64::EPSILON) - 1.0) * 100.0;
+
+                line.push_str(&format!("  {:+.1}% vs {}", change, previous.commit));
+
+                if change > threshold {
+                    line.push_str("  REGRESSION");
+
+                    regressions += 1;
+                }
+            }
+
+            println!("{}", line);
+
+            records.push(record);
+        }
+    }
+
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(&path)?;
+
+    for record in records {
+        writeln!(file, "{}", record)?;
+    }
+
+    if regressions > 0 {
+        println!("\n{} phases regressed by more than {}%", regressions, threshold);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_roundtrip() {
+        let line = "1733000000 af013aa 2024 6 input.txt part2 3 100 200 300";
+        let record = Record::parse(line).unwrap();
+
+        assert_eq!(record.phase, "part2");
+        assert_eq!(record.stats.median, Duration::from_nanos(200));
+        assert_eq!(record.to_string(), line);
+
+        assert_eq!(Record::parse("1733000000 af013aa 2024 6 input.txt part2"), None);
+        assert_eq!(Record::parse("1733000000 af013aa 2024 6 input.txt part2 3 100 x 300"), None);
+    }
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut runs = 0;
+        let (stats, _) = measure(10, Duration::ZERO, || { runs += 1; Ok(()) }).unwrap();
+
+        assert_eq!((runs, stats.iterations), (1, 1));
+    }
+}
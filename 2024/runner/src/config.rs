@@ -0,0 +1,105 @@
+use std::path::Path;
+use std::env;
+use std::fs;
+
+use aoc::ParseError;
+
+pub const FILE: &str = "aoc.conf";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+
+/// Settings for talking to the puzzle server, read from `aoc.conf` in the repo root as `key = value` lines.
+///
+/// `AOC_SESSION`, `AOC_URL` and `AOC_BACKEND` take precedence over the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub url: String,
+    pub backend: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            session: None,
+            url: String::from(BASE_URL),
+            backend: None,
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(input: &str) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| ParseError::at(input, line, "expected a setting like session = <token>"))?;
+
+            let value = value.trim().to_string();
+
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "url" => config.url = value,
+                "backend" => config.backend = Some(value),
+                _ => return Err(ParseError::at(input, key.trim(), "unknown setting")),
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn load(root: &Path) -> aoc::Result<Config> {
+        let path = root.join(FILE);
+
+        let mut config = if path.exists() {
+            Config::parse(&fs::read_to_string(&path)?).map_err(|err| err.with_file(&path.display().to_string()))?
+        } else {
+            Config::default()
+        };
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+
+        if let Ok(url) = env::var("AOC_URL") {
+            config.url = url;
+        }
+
+        if let Ok(backend) = env::var("AOC_BACKEND") {
+            config.backend = Some(backend);
+        }
+
+        config.url = config.url.trim_end_matches('/').to_string();
+
+        Ok(config)
+    }
+
+    pub fn session(&self) -> aoc::Result<&str> {
+        self.session.as_deref()
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| format!("no session token, set AOC_SESSION or `session = ...` in {}", FILE).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings() {
+        let config = Config::parse("# token from the browser\nsession = abc123\nurl = http://127.0.0.1:8080\n").unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.url, "http://127.0.0.1:8080");
+        assert_eq!(config.backend, None);
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Config::parse("session abc").unwrap_err().to_string(), "1:1: expected a setting like session = <token> 'session abc'");
+        assert_eq!(Config::parse("url = x\ncolour = red").unwrap_err().to_string(), "2:1: unknown setting 'colour'");
+    }
+}
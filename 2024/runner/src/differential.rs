@@ -0,0 +1,61 @@
+use std::panic;
+
+use aoc::differential;
+use aoc::random::Rng;
+
+use crate::args::Args;
+use crate::registry::DAYS;
+
+const RUNS: u64 = 100;
+const SIZE: usize = 20;
+
+
+pub fn differential(args: &Args) -> aoc::Result<()> {
+    let (year, day) = args.selection()?;
+
+    let runs = args.option_as::<u64>("runs")?.unwrap_or(RUNS);
+    let size = args.option_as::<usize>("size")?.unwrap_or(SIZE).max(1);
+    let seed = args.option_as::<u64>("seed")?.unwrap_or_else(Rng::seed);
+
+    let days = aoc::registry::select(DAYS, year, day)?
+        .into_iter()
+        .filter(|day| day.generate.is_some() && day.reference.is_some())
+        .collect::<Vec<_>>();
+
+    // panics are reported with the mismatch, shrinking would otherwise print every one of them
+    let hook = panic::take_hook();
+
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut mismatches = 0;
+
+    for day in days {
+        // sizes grow from 1 so the cheap inputs go first
+        let mismatch = (0..runs)
+            .map(|run| (seed.wrapping_add(run), 1 + run as usize % size))
+            .map(|(seed, size)| differential::check(day, seed, size))
+            .find_map(|result| result.transpose());
+
+        match mismatch {
+            None => println!("{} day {}: {} runs agree (seeds from {})", day.year, day.day, runs, seed),
+            Some(Ok(mismatch)) => {
+                mismatches += 1;
+
+                print!("{} day {}: MISMATCH {}", day.year, day.day, mismatch);
+            },
+            Some(Err(err)) => {
+                panic::set_hook(hook);
+
+                return Err(err);
+            },
+        }
+    }
+
+    panic::set_hook(hook);
+
+    if mismatches > 0 {
+        return Err(format!("{} days disagree with their reference", mismatches).into());
+    }
+
+    Ok(())
+}
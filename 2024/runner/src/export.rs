@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+
+use aoc::image::{Format, Image, Sequence};
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+const SCALE: usize = 4;
+const LIMIT: usize = 1000;
+
+
+pub fn export(args: &Args) -> aoc::Result<()> {
+    let (year, day) = match args.selection()? {
+        (Some(year), Some(day)) => (year, day),
+        _ => return Err("export needs a year and a day".into()),
+    };
+
+    let day = aoc::registry::select(DAYS, Some(year), Some(day))?[0];
+    let input = input::load(day, args.option("input"))?;
+    let solution = (day.parse)(&input.content)
+        .map_err(|err| aoc::error::with_file(err, &input.name))?;
+
+    // frames of the animation instead of the picture of the result
+    if let Some(dir) = args.option("frames") {
+        let format = args.option_as::<Format>("format")?.unwrap_or(Format::Png);
+        let scale = args.option_as::<usize>("scale")?.unwrap_or(SCALE);
+        let limit = args.option_as::<usize>("limit")?.unwrap_or(LIMIT);
+
+        let mut animation = solution.animation()
+            .ok_or_else(|| format!("{} day {} has no animation", day.year, day.day))?;
+
+        let mut sequence = Sequence::new(&PathBuf::from(dir), format)?;
+
+        while sequence.len() < limit {
+            let Some(frame) = animation.frame() else {
+                break;
+            };
+
+            sequence.push(&Image::from_frame(&frame, scale))?;
+        }
+
+        println!("wrote {} frames to {}", sequence.len(), dir);
+
+        return Ok(());
+    }
+
+    let image = solution.image()
+        .ok_or_else(|| format!("{} day {} has no image", day.year, day.day))?;
+
+    let path = args.option("output")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(format!("{}-day{}.png", day.year, day.day)));
+
+    image.save(&path)?;
+
+    println!("wrote {}x{} image to {}", image.width(), image.height(), path.display());
+
+    Ok(())
+}
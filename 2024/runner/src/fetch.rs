@@ -0,0 +1,115 @@
+use std::path::Path;
+use std::fs;
+
+use crate::config::Config;
+use crate::http::{self, Http, Method, Request};
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+
+/// Downloads the input into `path` unless it is already there, returns whether anything was downloaded.
+///
+/// An empty file is the placeholder `new` leaves behind and doesn't count.
+pub fn fetch_input(http: &dyn Http, config: &Config, year: u32, day: u32, path: &Path) -> aoc::Result<bool> {
+    if path.metadata().is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(false);
+    }
+
+    let response = http.send(&Request {
+        method: Method::Get,
+        url: format!("{}/{}/day/{}/input", config.url, year, day),
+        session: Some(config.session()?),
+        form: None,
+    }).map_err(|err| format!("failed to fetch {} day {}: {}", year, day, err))?;
+
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or("").trim();
+
+        return Err(format!("failed to fetch {} day {}: status {} {}", year, day, response.status, reason).into());
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, response.body)?;
+
+    Ok(true)
+}
+
+pub fn fetch(args: &Args) -> aoc::Result<()> {
+    let root = input::root();
+    let config = Config::load(&root)?;
+    let http = http::backend(&config)?;
+
+    // a single day doesn't have to be registered yet, that's usually when its input is needed
+    let days = match args.selection()? {
+        (Some(year), Some(day)) => vec![(year, day)],
+        (year, day) => aoc::registry::select(DAYS, year, day)?.iter().map(|day| (day.year, day.day)).collect(),
+    };
+
+    for (year, day) in days {
+        let path = aoc::registry::dir(&root, year, day).join(input::DEFAULT);
+
+        match fetch_input(http.as_ref(), &config, year, day, &path)? {
+            true => println!("{} day {}: fetched {}", year, day, path.display()),
+            false => println!("{} day {}: cached {}", year, day, path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+    use std::env;
+
+    use super::*;
+    use crate::http::{mock, Tcp};
+
+    fn config(url: String) -> Config {
+        Config {
+            session: Some(String::from("secret")),
+            url,
+            backend: None,
+        }
+    }
+
+    #[test]
+    fn caches() {
+        let (url, server) = mock::serve(vec![mock::respond(200, "3   4\n4   3\n")]);
+        let path = env::temp_dir().join(format!("aoc-fetch-{}", process::id())).join("day1").join(input::DEFAULT);
+
+        // the empty placeholder left by `new` gets replaced
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+
+        assert!(fetch_input(&Tcp, &config(url.clone()), 2024, 1, &path).unwrap());
+        assert!(!fetch_input(&Tcp, &config(url), 2024, 1, &path).unwrap());
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+
+        let requests = server.join().unwrap();
+
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn rejected() {
+        let (url, server) = mock::serve(vec![mock::respond(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+        let path = env::temp_dir().join(format!("aoc-rejected-{}", process::id())).join(input::DEFAULT);
+
+        let err = fetch_input(&Tcp, &config(url), 2024, 1, &path).unwrap_err();
+
+        assert_eq!(err.to_string(), "failed to fetch 2024 day 1: status 400 Puzzle inputs differ by user.  Please log in to get your puzzle input.");
+        assert!(!path.exists());
+
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,43 @@
+use std::fs;
+
+use aoc::random::Rng;
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+const SIZE: usize = 100;
+
+
+pub fn generate(args: &Args) -> aoc::Result<()> {
+    let (year, day) = match args.selection()? {
+        (Some(year), Some(day)) => (year, day),
+        _ => return Err("generate needs a year and a day".into()),
+    };
+
+    let day = aoc::registry::select(DAYS, Some(year), Some(day))?[0];
+    let generate = day.generate
+        .ok_or_else(|| format!("{} day {} has no input generator", day.year, day.day))?;
+
+    let size = args.option_as::<usize>("size")?.unwrap_or(SIZE);
+    let seed = args.option_as::<u64>("seed")?.unwrap_or_else(Rng::seed);
+
+    let text = generate(&mut Rng::new(seed), size);
+
+    // the seed goes to stderr so the input on stdout can be piped straight into `run --input -`
+    eprintln!("{} day {} size {} seed {}", day.year, day.day, size, seed);
+
+    match args.option("output") {
+        Some(input::DEFAULT) => Err(format!("refusing to replace the puzzle input {}", input::DEFAULT).into()),
+        Some(name) => {
+            let path = input::path(day, name);
+
+            fs::write(&path, text).map_err(|err| format!("{}: {}", path.display(), err).into())
+        },
+        None => {
+            print!("{}", text);
+
+            Ok(())
+        },
+    }
+}
@@ -0,0 +1,254 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use crate::config::Config;
+
+const USER_AGENT: &str = "github.com/proxin187/advent-of-code by proxin187";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+        }
+    }
+}
+
+pub struct Request<'a> {
+    pub method: Method,
+    pub url: String,
+    pub session: Option<&'a str>,
+    pub form: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Anything that can carry a request to the puzzle server, so the commands can be pointed at a stand-in.
+pub trait Http {
+    fn send(&self, request: &Request) -> aoc::Result<Response>;
+}
+
+/// Picks the backend from the config, plain `http://` is spoken directly and anything else goes through curl.
+pub fn backend(config: &Config) -> aoc::Result<Box<dyn Http>> {
+    match config.backend.as_deref() {
+        Some("tcp") => Ok(Box::new(Tcp)),
+        Some("curl") => Ok(Box::new(Curl)),
+        Some(backend) => Err(format!("unknown http backend '{}', expected tcp or curl", backend).into()),
+        None if config.url.starts_with("http://") => Ok(Box::new(Tcp)),
+        None => Ok(Box::new(Curl)),
+    }
+}
+
+/// Minimal HTTP/1.1 over a plain socket, enough for a local server.
+pub struct Tcp;
+
+impl Tcp {
+    fn split(url: &str) -> aoc::Result<(&str, &str)> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| format!("the tcp backend only speaks http, got '{}'", url))?;
+
+        Ok(match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        })
+    }
+
+    fn dechunk(body: &str) -> aoc::Result<String> {
+        let mut decoded = String::new();
+        let mut rest = body;
+
+        loop {
+            let (size, tail) = rest.split_once("\r\n").ok_or("truncated chunked body")?;
+            let size = usize::from_str_radix(size.split(';').next().unwrap_or("").trim(), 16)?;
+
+            if size == 0 {
+                return Ok(decoded);
+            }
+
+            decoded.push_str(tail.get(..size).ok_or("truncated chunked body")?);
+            rest = tail[size..].strip_prefix("\r\n").ok_or("malformed chunked body")?;
+        }
+    }
+
+    pub fn parse(raw: &str) -> aoc::Result<Response> {
+        let (head, body) = raw.split_once("\r\n\r\n").ok_or("malformed http response")?;
+        let mut lines = head.lines();
+
+        let status = lines.next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or("malformed http status line")?;
+
+        let chunked = lines.any(|line| {
+            line.split_once(':').is_some_and(|(name, value)| {
+                name.eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+            })
+        });
+
+        Ok(Response {
+            status,
+            body: if chunked { Tcp::dechunk(body)? } else { body.to_string() },
+        })
+    }
+}
+
+impl Http for Tcp {
+    fn send(&self, request: &Request) -> aoc::Result<Response> {
+        let (host, path) = Tcp::split(&request.url)?;
+
+        let mut stream = TcpStream::connect(host)?;
+
+        stream.set_read_timeout(Some(TIMEOUT))?;
+
+        let mut message = format!("{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n", request.method.as_str(), path, host, USER_AGENT);
+
+        if let Some(session) = request.session {
+            message.push_str(&format!("Cookie: session={}\r\n", session));
+        }
+
+        if let Some(form) = &request.form {
+            message.push_str(&format!("Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{}", form.len(), form));
+        } else {
+            message.push_str("\r\n");
+        }
+
+        stream.write_all(message.as_bytes())?;
+
+        let mut raw = String::new();
+
+        stream.read_to_string(&mut raw)?;
+
+        Tcp::parse(&raw)
+    }
+}
+
+/// Shells out to curl, which takes care of https.
+pub struct Curl;
+
+impl Http for Curl {
+    fn send(&self, request: &Request) -> aoc::Result<Response> {
+        let mut command = Command::new("curl");
+
+        command.args(["--silent", "--show-error", "--max-time", &TIMEOUT.as_secs().to_string()])
+            .args(["--user-agent", USER_AGENT])
+            .args(["--request", request.method.as_str()])
+            .args(["--write-out", "\n%{http_code}"]);
+
+        if let Some(form) = &request.form {
+            command.args(["--data", form]);
+        }
+
+        // the cookie goes in through a config on stdin so the token doesn't show up in the process list
+        if request.session.is_some() {
+            command.args(["--config", "-"]).stdin(Stdio::piped());
+        }
+
+        let mut child = command.arg(&request.url)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to run curl: {}", err))?;
+
+        if let (Some(session), Some(mut stdin)) = (request.session, child.stdin.take()) {
+            writeln!(stdin, "cookie = \"session={}\"", session)?;
+        }
+
+        let output = child.wait_with_output()?;
+
+        if !output.status.success() {
+            return Err(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim()).into());
+        }
+
+        let output = String::from_utf8(output.stdout)?;
+        let (body, status) = output.rsplit_once('\n').ok_or("curl printed no status code")?;
+
+        Ok(Response {
+            status: status.trim().parse()?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// A local stand-in for the puzzle server, answers each connection with the next canned response.
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    pub fn respond(status: u16, body: &str) -> String {
+        format!("HTTP/1.1 {} Mock\r\nContent-Length: {}\r\n\r\n{}", status, body.len(), body)
+    }
+
+    /// Returns the base url and a handle that yields the requests that were received.
+    pub fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses.into_iter()
+                .map(|response| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+
+                    // headers end with an empty line
+                    while reader.read_line(&mut request).unwrap() > 2 {}
+
+                    let length = request.lines()
+                        .find_map(|line| line.strip_prefix("Content-Length: "))
+                        .map_or(0, |length| length.parse::<usize>().unwrap());
+
+                    let mut body = vec![0; length];
+
+                    reader.read_exact(&mut body).unwrap();
+                    request.push_str(&String::from_utf8(body).unwrap());
+
+                    stream.write_all(response.as_bytes()).unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses() {
+        let response = Tcp::parse("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1 2\n").unwrap();
+
+        assert_eq!((response.status, response.body.as_str()), (200, "1 2\n"));
+
+        let response = Tcp::parse("HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n").unwrap();
+
+        assert_eq!((response.status, response.body.as_str()), (404, "abcde"));
+
+        assert!(Tcp::parse("garbage").is_err());
+    }
+
+    #[test]
+    fn urls() {
+        assert_eq!(Tcp::split("http://127.0.0.1:8080/2024/day/1/input").unwrap(), ("127.0.0.1:8080", "/2024/day/1/input"));
+        assert_eq!(Tcp::split("http://localhost").unwrap(), ("localhost", "/"));
+        assert!(Tcp::split("https://adventofcode.com").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::{Path, PathBuf};
+use std::env;
+
+use aoc::Day;
+
+pub const DEFAULT: &str = "input.txt";
+
+
+pub struct Input {
+    pub name: String,
+    pub content: String,
+}
+
+pub fn root() -> PathBuf {
+    env::var_os("AOC_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."))
+        .canonicalize()
+        .unwrap_or_else(|_| PathBuf::from("."))
+}
+
+/// A bare file name lives in the day directory, anything with a directory in it is taken as is.
+pub fn path(day: &Day, name: &str) -> PathBuf {
+    let path = Path::new(name);
+
+    if path.components().count() > 1 || path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        day.dir(&root()).join(name)
+    }
+}
+
+pub fn load(day: &Day, name: Option<&str>) -> aoc::Result<Input> {
+    match name.unwrap_or(DEFAULT) {
+        "-" => Ok(Input {
+            name: String::from("<stdin>"),
+            content: aoc::input::stdin()?,
+        }),
+        name => {
+            let path = path(day, name).display().to_string();
+
+            Ok(Input {
+                content: aoc::input::read(&path)?,
+                name: path,
+            })
+        },
+    }
+}
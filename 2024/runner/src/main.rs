@@ -0,0 +1,105 @@
+mod animate;
+mod args;
+mod bench;
+mod config;
+mod differential;
+mod export;
+mod fetch;
+mod generate;
+mod http;
+mod input;
+mod new;
+mod registry;
+mod run;
+mod submit;
+mod verify;
+
+use std::process::ExitCode;
+use std::env;
+
+use args::Args;
+
+const USAGE: &str = "usage: aoc <command> [options]
+
+commands:
+    run [YEAR [DAY]] [--part N] [--input FILE] [--explain]
+                                                  run solutions, every registered day if none is given
+                                                  FILE is looked up in the day directory, - reads stdin
+                                                  --explain shows how days that can explain themselves got there
+    verify [YEAR [DAY]] [--input FILE] [--record] check solutions against the answers.txt of every day
+                                                  --record stores answers that aren't known yet
+    bench [YEAR [DAY]] [--input FILE] [--iterations N] [--budget SECS] [--threshold PCT] [--history FILE]
+                                                  time parsing and both parts, defaults to 10 iterations
+                                                  or 5 seconds per phase, results are appended to bench.txt
+                                                  and medians more than PCT (10) slower than the last run are flagged
+    fetch [YEAR [DAY]]                            download inputs into the day directory unless they're already there
+                                                  the session token and server come from aoc.conf or $AOC_SESSION
+    submit YEAR DAY --part N [--answer VALUE] [--input FILE]
+                                                  send an answer, solving input.txt if none is given
+                                                  answers known to be wrong and cooldowns are refused from
+                                                  the submissions.txt of the day
+    new YEAR DAY                                  scaffold a crate for a day and register it with the runner
+    animate YEAR DAY [--input FILE] [--fps N] [--step] [--plain]
+                                                  watch a solution work frame by frame, at 10 frames per second
+                                                  by default, --step starts paused and --plain drops colours
+                                                  type a line while it runs: enter pauses or steps, p resumes,
+                                                  + and - change speed and q quits
+    export YEAR DAY [--input FILE] [--output FILE]
+                                                  save a picture of the solution, .png or .ppm by extension,
+                                                  to YEAR-dayDAY.png by default
+    export YEAR DAY --frames DIR [--format png|ppm] [--scale N] [--limit N]
+                                                  save the frames of the animation as numbered images,
+                                                  at most 1000 of them at 4 pixels per cell by default
+    generate YEAR DAY [--size N] [--seed S] [--output FILE]
+                                                  write a random input of about N (100) lines or cells to
+                                                  stdout or FILE, the seed is printed to stderr so it can be
+                                                  reproduced
+    differential [YEAR [DAY]] [--runs N] [--size N] [--seed S]
+                                                  compare solutions with their slow reference solvers on N (100)
+                                                  generated inputs of sizes up to 20, any mismatch is shrunk
+                                                  to a minimal input and printed with its seed
+    help                                          show this message
+
+options:
+    --log FILTER    log to stderr, e.g. `debug` or `warn,day6=trace`, defaults to $AOC_LOG";
+
+
+fn dispatch(args: &Args) -> aoc::Result<()> {
+    aoc::log::init(args.option("log"))?;
+
+    if args.switch("help") {
+        println!("{}", USAGE);
+
+        return Ok(());
+    }
+
+    match args.command.as_deref() {
+        Some("run") => run::run(args),
+        Some("verify") => verify::verify(args),
+        Some("bench") => bench::bench(args),
+        Some("fetch") => fetch::fetch(args),
+        Some("submit") => submit::submit(args),
+        Some("new") => new::new(args),
+        Some("animate") => animate::animate(args),
+        Some("export") => export::export(args),
+        Some("generate") => generate::generate(args),
+        Some("differential") => differential::differential(args),
+        Some("help") | None => {
+            println!("{}", USAGE);
+
+            Ok(())
+        },
+        Some(command) => Err(format!("unknown command '{}'\n\n{}", command, USAGE).into()),
+    }
+}
+
+fn main() -> ExitCode {
+    match Args::parse(env::args().skip(1)).and_then(|args| dispatch(&args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+
+            ExitCode::FAILURE
+        },
+    }
+}
@@ -0,0 +1,186 @@
+use std::path::Path;
+use std::fs;
+
+use aoc::answers;
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+/// Year of the cargo workspace every day is a member of, whatever year it belongs to.
+const WORKSPACE: u32 = 2024;
+
+const LIB: &str = "use aoc::{Answer, Solution};
+
+
+pub struct Part1 {
+    lines: Vec<String>,
+}
+
+impl Solution for Part1 {
+    fn parse(input: &str) -> aoc::Result<Part1> {
+        Ok(Part1 {
+            lines: input.lines().map(String::from).collect(),
+        })
+    }
+
+    fn part1(&self) -> aoc::Result<Answer> {
+        Err(format!(\"part 1 isn't solved yet, the input has {} lines\", self.lines.len()).into())
+    }
+
+    fn part2(&self) -> aoc::Result<Answer> {
+        Err(format!(\"part 2 isn't solved yet, the input has {} lines\", self.lines.len()).into())
+    }
+}
+";
+
+
+/// Days of the workspace year keep their short names, other years are prefixed so they can't collide.
+fn package(year: u32, day: u32) -> String {
+    match year {
+        WORKSPACE => format!("day{}", day),
+        _ => format!("aoc{}-day{}", year, day),
+    }
+}
+
+/// Path of the day relative to a crate directory of the workspace.
+fn relative(year: u32, day: u32) -> String {
+    match year {
+        WORKSPACE => format!("../day{}", day),
+        _ => format!("../../{}/day{}", year, day),
+    }
+}
+
+fn manifest(year: u32, day: u32) -> String {
+    let (workspace, aoc) = match year {
+        WORKSPACE => (String::new(), String::from("../aoc")),
+        _ => (format!("workspace = \"../../{}\"\n", WORKSPACE), format!("../../{}/aoc", WORKSPACE)),
+    };
+
+    format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n{}\n[dependencies]\naoc = {{ path = \"{}\" }}\n",
+        package(year, day), workspace, aoc,
+    )
+}
+
+/// Inserts `line` right before the first line that is exactly `before`.
+fn insert_before(text: &str, before: &str, line: &str) -> aoc::Result<String> {
+    let index = text.lines()
+        .position(|existing| existing == before)
+        .ok_or_else(|| format!("expected a line '{}'", before))?;
+
+    let mut lines = text.lines().collect::<Vec<&str>>();
+
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn edit(path: &Path, edit: impl FnOnce(&str) -> aoc::Result<String>) -> aoc::Result<()> {
+    let text = fs::read_to_string(path).map_err(|err| format!("failed to read {}: {}", path.display(), err))?;
+
+    fs::write(path, edit(&text).map_err(|err| format!("{}: {}", path.display(), err))?)?;
+
+    Ok(())
+}
+
+/// Creates the crate for a day under `root` and wires it into the workspace, the runner and its registry.
+pub fn scaffold(root: &Path, year: u32, day: u32) -> aoc::Result<()> {
+    let dir = aoc::registry::dir(root, year, day);
+    let workspace = root.join(WORKSPACE.to_string());
+    let runner = workspace.join("runner");
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    fs::create_dir_all(dir.join("src"))?;
+
+    fs::write(dir.join("Cargo.toml"), manifest(year, day))?;
+    fs::write(dir.join("src").join("lib.rs"), LIB)?;
+    fs::write(dir.join(input::DEFAULT), "")?;
+    fs::write(dir.join("test.txt"), "")?;
+    fs::write(dir.join(answers::FILE), "# input part answer\n")?;
+
+    let member = relative(year, day);
+    let member = member.strip_prefix("../").unwrap_or(&member);
+
+    edit(&workspace.join("Cargo.toml"), |text| insert_before(text, "    \"runner\",", &format!("    \"{}\",", member)))?;
+
+    edit(&runner.join("Cargo.toml"), |text| {
+        Ok(format!("{}{} = {{ path = \"{}\" }}\n", text, package(year, day), relative(year, day)))
+    })?;
+
+    edit(&runner.join("src").join("registry.rs"), |text| {
+        let line = format!("    Day::new::<{}::Part1>({}, {}),", package(year, day).replace('-', "_"), year, day);
+
+        insert_before(text, "];", &line)
+    })?;
+
+    Ok(())
+}
+
+pub fn new(args: &Args) -> aoc::Result<()> {
+    let (year, day) = match args.selection()? {
+        (Some(year), Some(day)) => (year, day),
+        _ => return Err("new needs a year and a day".into()),
+    };
+
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {}, days go from 1 to 25", day).into());
+    }
+
+    if DAYS.iter().any(|known| known.year == year && known.day == day) {
+        return Err(format!("{} day {} is already registered", year, day).into());
+    }
+
+    let root = input::root();
+
+    scaffold(&root, year, day)?;
+
+    println!("created {} as {}", aoc::registry::dir(&root, year, day).display(), package(year, day));
+    println!("fill in test.txt and its answers in answers.txt, `cargo test` fails on the example until they match");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert_eq!((package(2024, 12), relative(2024, 12)), (String::from("day12"), String::from("../day12")));
+        assert_eq!((package(2023, 1), relative(2023, 1)), (String::from("aoc2023-day1"), String::from("../../2023/day1")));
+
+        assert!(manifest(2023, 1).contains("workspace = \"../../2024\"\n"));
+        assert!(!manifest(2024, 12).contains("workspace"));
+    }
+
+    #[test]
+    fn scaffolds() {
+        let root = env::temp_dir().join(format!("aoc-new-{}", process::id()));
+        let runner = root.join("2024").join("runner");
+
+        fs::create_dir_all(runner.join("src")).unwrap();
+
+        fs::write(root.join("2024").join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc\",\n    \"runner\",\n]\n").unwrap();
+        fs::write(runner.join("Cargo.toml"), "[dependencies]\naoc = { path = \"../aoc\" }\n").unwrap();
+        fs::write(runner.join("src").join("registry.rs"), "pub const DAYS: &[Day] = &[\n];\n").unwrap();
+
+        scaffold(&root, 2024, 12).unwrap();
+        scaffold(&root, 2023, 1).unwrap();
+
+        assert_eq!(fs::read_to_string(root.join("2024").join("Cargo.toml")).unwrap(), "[workspace]\nmembers = [\n    \"aoc\",\n    \"day12\",\n    \"../2023/day1\",\n    \"runner\",\n]\n");
+        assert_eq!(fs::read_to_string(runner.join("Cargo.toml")).unwrap(), "[dependencies]\naoc = { path = \"../aoc\" }\nday12 = { path = \"../day12\" }\naoc2023-day1 = { path = \"../../2023/day1\" }\n");
+        assert_eq!(fs::read_to_string(runner.join("src").join("registry.rs")).unwrap(), "pub const DAYS: &[Day] = &[\n    Day::new::<day12::Part1>(2024, 12),\n    Day::new::<aoc2023_day1::Part1>(2023, 1),\n];\n");
+
+        assert_eq!(fs::read_to_string(root.join("2023").join("day1").join("test.txt")).unwrap(), "");
+        assert!(scaffold(&root, 2024, 12).unwrap_err().to_string().ends_with("already exists"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
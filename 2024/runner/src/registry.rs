@@ -0,0 +1,103 @@
+use aoc::Day;
+
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Part1>(2024, 1).generator(day1::generate).reference(day1::reference),
+    Day::new::<day2::Data>(2024, 2).generator(day2::generate).reference(day2::reference),
+    Day::new::<day3::Memory>(2024, 3).generator(day3::generate).reference(day3::reference),
+    Day::new::<day4::Part1>(2024, 4).generator(day4::generate).reference(day4::reference),
+    Day::new::<day5::Part1>(2024, 5).generator(day5::generate).reference(day5::reference),
+    Day::new::<day6::Part1>(2024, 6).generator(day6::generate).reference(day6::reference),
+    Day::new::<day7::Part1>(2024, 7).generator(day7::generate).reference(day7::reference),
+    Day::new::<day8::Part1>(2024, 8).generator(day8::generate).reference(day8::reference),
+    Day::new::<day9::Part1>(2024, 9).generator(day9::generate).reference(day9::reference),
+    Day::new::<day10::Part1>(2024, 10).generator(day10::generate).reference(day10::reference),
+    Day::new::<day11::Part1>(2024, 11).generator(day11::generate).reference(day11::reference),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc::answers::{self, Answers};
+    use aoc::differential;
+    use aoc::random::Rng;
+    use aoc::Part;
+
+    use super::*;
+    use crate::input;
+
+    /// Every `test*.txt` next to a day is an example, its expected answers live in the answers.txt of that day.
+    #[test]
+    fn examples() {
+        let mut failures = Vec::new();
+
+        for day in DAYS {
+            let dir = day.dir(&input::root());
+            let answers = Answers::load(&dir.join(answers::FILE)).unwrap();
+
+            let mut fixtures = fs::read_dir(&dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .filter(|name| name.starts_with("test") && name.ends_with(".txt"))
+                .collect::<Vec<String>>();
+
+            fixtures.sort();
+
+            for name in fixtures {
+                let input = input::load(day, Some(&name)).unwrap();
+                let solution = (day.parse)(&input.content).unwrap_or_else(|err| panic!("{}: {}", input.name, err));
+
+                for part in Part::ALL {
+                    let answer = part.solve(solution.as_ref()).map(|answer| answer.to_string());
+
+                    match (answers.get(&name, part), answer) {
+                        (Some(expected), Ok(answer)) if answer == expected => {},
+                        (expected, answer) => failures.push(format!(
+                            "{} day {} part {} {}: got {:?}, expected {}",
+                            day.year, day.day, part, name, answer, expected.unwrap_or("an answer in answers.txt"),
+                        )),
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    /// Generated inputs have to parse and be solvable, whatever their seed and size.
+    #[test]
+    fn generated() {
+        for day in DAYS {
+            // freshly scaffolded days don't have a generator yet
+            let Some(generate) = day.generate else {
+                continue;
+            };
+
+            for (seed, size) in [(0, 1), (1, 2), (2, 10), (3, 30)] {
+                let input = generate(&mut Rng::new(seed), size);
+                let solution = (day.parse)(&input).unwrap_or_else(|err| panic!("day {} seed {} size {}: {}\n{}", day.day, seed, size, err, input));
+
+                for part in Part::ALL {
+                    if let Err(err) = part.solve(solution.as_ref()) {
+                        panic!("day {} part {} seed {} size {}: {}\n{}", day.day, part, seed, size, err, input);
+                    }
+                }
+            }
+
+            assert_eq!(generate(&mut Rng::new(7), 20), generate(&mut Rng::new(7), 20), "day {} isn't reproducible", day.day);
+        }
+    }
+
+    /// A few small generated inputs per day, `aoc differential` runs many more.
+    #[test]
+    fn references() {
+        for day in DAYS.iter().filter(|day| day.generate.is_some() && day.reference.is_some()) {
+            for seed in 0..4 {
+                if let Some(mismatch) = differential::check(day, seed, 1 + seed as usize % 2).unwrap() {
+                    panic!("{} day {}: {}", day.year, day.day, mismatch);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use aoc::Part;
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+
+pub fn run(args: &Args) -> aoc::Result<()> {
+    let (year, day) = args.selection()?;
+
+    let parts = args.option_as::<Part>("part")?
+        .map(|part| vec![part])
+        .unwrap_or(Part::ALL.to_vec());
+
+    let days = aoc::registry::select(DAYS, year, day)?;
+
+    if args.option("input") == Some("-") && days.len() > 1 {
+        return Err("reading from stdin requires a single day".into());
+    }
+
+    for day in days {
+        let input = input::load(day, args.option("input"))?;
+        let solution = (day.parse)(&input.content)
+            .map_err(|err| aoc::error::with_file(err, &input.name))?;
+
+        for part in parts.iter() {
+            println!("{} day {} part {}: {}", day.year, day.day, part, part.solve(solution.as_ref())?);
+
+            if args.switch("explain") {
+                match solution.explain(*part) {
+                    Some(explanation) => print!("{}", explanation),
+                    None => println!("{} day {} has no explanation", day.year, day.day),
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
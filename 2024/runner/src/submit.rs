@@ -0,0 +1,426 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::io::Write;
+use std::path::Path;
+use std::fmt;
+use std::fs;
+
+use aoc::answers::{self, Answers};
+use aoc::{ParseError, Part};
+
+use crate::config::Config;
+use crate::http::{self, Http, Method, Request};
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+pub const LOG: &str = "submissions.txt";
+
+/// How long the server makes us wait after a wrong answer when it doesn't say.
+const PENALTY: u64 = 60;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait,
+    Solved,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] = [Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong, Verdict::Wait, Verdict::Solved];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "high",
+            Verdict::TooLow => "low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::Solved => "solved",
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "that's the right answer"),
+            Verdict::TooHigh => write!(f, "that's not the right answer, it's too high"),
+            Verdict::TooLow => write!(f, "that's not the right answer, it's too low"),
+            Verdict::Wrong => write!(f, "that's not the right answer"),
+            Verdict::Wait => write!(f, "an answer was given too recently"),
+            Verdict::Solved => write!(f, "this part is already solved"),
+        }
+    }
+}
+
+/// Parses "1m 5s", "34s" or "one minute" like the server words its waiting times.
+fn seconds(text: &str) -> Option<u64> {
+    let words = text.split_whitespace().collect::<Vec<&str>>();
+
+    if let Some(index) = words.iter().position(|word| word.starts_with("minute")) {
+        let count = words.get(index.checked_sub(1)?)?;
+
+        return match *count {
+            "one" | "a" => Some(60),
+            count => count.parse::<u64>().ok().map(|minutes| minutes * 60),
+        };
+    }
+
+    words.iter()
+        .map(|word| word.trim_end_matches(|c: char| !c.is_ascii_alphanumeric()))
+        .take_while(|word| word.ends_with('m') || word.ends_with('s'))
+        .map(|word| match word.split_at(word.len() - 1) {
+            (count, "m") => count.parse::<u64>().ok().map(|minutes| minutes * 60),
+            (count, _) => count.parse::<u64>().ok(),
+        })
+        .sum::<Option<u64>>()
+        .filter(|seconds| *seconds > 0)
+}
+
+/// Reads the verdict out of the page the server answers with.
+pub fn parse_response(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::Solved
+    } else if body.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if body.contains("your answer is too low") {
+        Verdict::TooLow
+    } else {
+        Verdict::Wrong
+    }
+}
+
+/// Reads how many seconds the server wants us to wait before the next answer.
+pub fn parse_cooldown(body: &str) -> Option<u64> {
+    let wait = body.find("You have ").map(|index| &body[index + "You have ".len()..])
+        .and_then(|rest| rest.find(" left to wait").map(|end| &rest[..end]))
+        .or_else(|| {
+            let index = body.find("lease wait ")?;
+            let rest = &body[index + "lease wait ".len()..];
+
+            Some(&rest[..rest.find(" before")?])
+        })?;
+
+    seconds(wait)
+}
+
+/// The part of a day an answer is meant for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Submission {
+    pub timestamp: u64,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub cooldown: u64,
+    pub answer: String,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {} {}", self.timestamp, self.part, self.verdict.name(), self.cooldown, self.answer)
+    }
+}
+
+/// Every submission of a day, one `<timestamp> <part> <verdict> <cooldown> <answer>` per line.
+#[derive(Debug, Default)]
+pub struct Log {
+    pub submissions: Vec<Submission>,
+}
+
+impl Log {
+    pub fn parse(input: &str) -> Result<Log, ParseError> {
+        let submissions = input.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let mut fields = line.splitn(5, ' ');
+                let mut field = |what: &str| fields.next().ok_or_else(|| ParseError::at(input, line, &format!("missing {}", what)));
+
+                let timestamp = field("timestamp")?;
+                let part = field("part")?;
+                let verdict = field("verdict")?;
+                let cooldown = field("cooldown")?;
+                let answer = field("answer")?;
+
+                Ok(Submission {
+                    timestamp: aoc::error::parse(input, timestamp, "timestamp")?,
+                    part: aoc::error::parse(input, part, "part")?,
+                    verdict: Verdict::ALL.into_iter()
+                        .find(|known| known.name() == verdict)
+                        .ok_or_else(|| ParseError::at(input, verdict, "invalid verdict"))?,
+                    cooldown: aoc::error::parse(input, cooldown, "cooldown")?,
+                    answer: answer.to_string(),
+                })
+            })
+            .collect::<Result<Vec<Submission>, ParseError>>()?;
+
+        Ok(Log { submissions })
+    }
+
+    pub fn load(path: &Path) -> aoc::Result<Log> {
+        if !path.exists() {
+            return Ok(Log::default());
+        }
+
+        Log::parse(&fs::read_to_string(path)?).map_err(|err| err.with_file(&path.display().to_string()).into())
+    }
+
+    pub fn append(&mut self, path: &Path, submission: Submission) -> aoc::Result<()> {
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+
+        writeln!(file, "{}", submission)?;
+
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+
+    /// Decides from earlier submissions whether sending `answer` could be of any use.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(ready) = self.submissions.iter().map(|submission| submission.timestamp + submission.cooldown).max().filter(|ready| *ready > now) {
+            return Err(format!("the server asked to wait, try again in {}s", ready - now));
+        }
+
+        let submissions = self.submissions.iter().filter(|submission| submission.part == part);
+
+        for submission in submissions {
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Err(format!("part {} is already solved with {}", part, submission.answer));
+                },
+                verdict if verdict.is_wrong() && submission.answer == answer => {
+                    return Err(format!("{} was already submitted for part {}: {}", answer, part, verdict));
+                },
+                Verdict::TooHigh | Verdict::TooLow => {
+                    if let (Ok(answer), Ok(known)) = (answer.parse::<i128>(), submission.answer.parse::<i128>()) {
+                        if submission.verdict == Verdict::TooHigh && answer >= known {
+                            return Err(format!("{} can't be right, {} was already too high", answer, known));
+                        }
+
+                        if submission.verdict == Verdict::TooLow && answer <= known {
+                            return Err(format!("{} can't be right, {} was already too low", answer, known));
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Percent encodes a form value, everything but letters, digits and `-._~` would change its meaning.
+fn encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn now() -> aoc::Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+/// Posts the answer unless the log already knows how it would go, and records what the server said.
+pub fn submit_answer(http: &dyn Http, config: &Config, puzzle: Puzzle, answer: &str, log: &Path, now: u64) -> aoc::Result<Verdict> {
+    let Puzzle { year, day, part } = puzzle;
+
+    // the log keeps one submission per line
+    if answer.contains(['\n', '\r']) {
+        return Err(format!("the answer to {} day {} part {} can't span lines: {:?}", year, day, part, answer).into());
+    }
+
+    let mut submissions = Log::load(log)?;
+
+    submissions.check(part, answer, now)?;
+
+    let response = http.send(&Request {
+        method: Method::Post,
+        url: format!("{}/{}/day/{}/answer", config.url, year, day),
+        session: Some(config.session()?),
+        form: Some(format!("level={}&answer={}", part, encode(answer))),
+    }).map_err(|err| format!("failed to submit {} day {}: {}", year, day, err))?;
+
+    if response.status != 200 {
+        return Err(format!("failed to submit {} day {}: status {}", year, day, response.status).into());
+    }
+
+    let verdict = parse_response(&response.body);
+
+    let cooldown = match verdict {
+        Verdict::Wait | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong => parse_cooldown(&response.body).unwrap_or(PENALTY),
+        Verdict::Correct | Verdict::Solved => 0,
+    };
+
+    submissions.append(log, Submission { timestamp: now, part, verdict, cooldown, answer: answer.to_string() })?;
+
+    Ok(verdict)
+}
+
+pub fn submit(args: &Args) -> aoc::Result<()> {
+    let (year, day) = match args.selection()? {
+        (Some(year), Some(day)) => (year, day),
+        _ => return Err("submit needs a year and a day".into()),
+    };
+
+    let part = args.option_as::<Part>("part")?.ok_or("submit needs --part")?;
+
+    let root = input::root();
+    let dir = aoc::registry::dir(&root, year, day);
+
+    let answer = match args.option("answer") {
+        Some(answer) => answer.to_string(),
+        None => {
+            let day = aoc::registry::select(DAYS, Some(year), Some(day))?[0];
+            let input = input::load(day, args.option("input"))?;
+            let solution = (day.parse)(&input.content).map_err(|err| aoc::error::with_file(err, &input.name))?;
+
+            part.solve(solution.as_ref())?.to_string()
+        },
+    };
+
+    let config = Config::load(&root)?;
+    let http = http::backend(&config)?;
+
+    let verdict = submit_answer(http.as_ref(), &config, Puzzle { year, day, part }, &answer, &dir.join(LOG), now()?)?;
+
+    println!("{} day {} part {}: {}: {}", year, day, part, answer, verdict);
+
+    if verdict == Verdict::Correct && args.option("input").is_none_or(|name| name == input::DEFAULT) {
+        let path = dir.join(answers::FILE);
+        let mut answers = Answers::load(&path)?;
+
+        if answers.get(input::DEFAULT, part).is_none() {
+            answers.set(input::DEFAULT, part, &answer);
+            answers.save(&path)?;
+        }
+    }
+
+    match verdict {
+        Verdict::Correct => Ok(()),
+        verdict => Err(verdict.to_string().into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+    use std::env;
+
+    use super::*;
+    use crate::http::{mock, Tcp};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2024/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian.</p></article>";
+
+    #[test]
+    fn responses() {
+        assert_eq!((parse_response(TOO_HIGH), parse_cooldown(TOO_HIGH)), (Verdict::TooHigh, Some(60)));
+        assert_eq!((parse_response(TOO_RECENT), parse_cooldown(TOO_RECENT)), (Verdict::Wait, Some(65)));
+        assert_eq!((parse_response(CORRECT), parse_cooldown(CORRECT)), (Verdict::Correct, None));
+
+        assert_eq!(parse_cooldown("Please wait 5 minutes before trying again."), Some(300));
+        assert_eq!(parse_cooldown("You have 34s left to wait."), Some(34));
+    }
+
+    #[test]
+    fn refusals() {
+        let log = Log::parse("100 1 high 60 500\n200 1 low 60 100\n300 2 wrong 60 42\n").unwrap();
+
+        assert!(log.check(Part::One, "300", 359).unwrap_err().contains("try again in 1s"));
+        assert_eq!(log.check(Part::One, "300", 360), Ok(()));
+        assert!(log.check(Part::One, "500", 360).unwrap_err().contains("already submitted"));
+        assert!(log.check(Part::One, "600", 360).unwrap_err().contains("too high"));
+        assert!(log.check(Part::One, "50", 360).unwrap_err().contains("too low"));
+        assert!(log.check(Part::Two, "42", 360).unwrap_err().contains("already submitted"));
+        assert_eq!(log.check(Part::Two, "43", 360), Ok(()));
+
+        let solved = Log::parse("100 1 correct 0 300\n").unwrap();
+
+        assert!(solved.check(Part::One, "301", 200).unwrap_err().contains("already solved"));
+        assert_eq!(solved.check(Part::Two, "301", 200), Ok(()));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Log::parse("100 3 high 60 500").unwrap_err().to_string(), "1:5: invalid part '3'");
+        assert_eq!(Log::parse("100 1 great 60 500").unwrap_err().to_string(), "1:7: invalid verdict 'great'");
+    }
+
+    #[test]
+    fn multiline() {
+        let log = env::temp_dir().join(format!("aoc-submit-multiline-{}.txt", process::id()));
+
+        let config = Config {
+            session: Some(String::from("secret")),
+            url: String::from("http://127.0.0.1:1"),
+            backend: None,
+        };
+
+        let puzzle = Puzzle { year: 2024, day: 1, part: Part::One };
+
+        for answer in ["12\n1000 1 correct 0 13", "12\r"] {
+            assert!(submit_answer(&Tcp, &config, puzzle, answer, &log, 1000).unwrap_err().to_string().contains("can't span lines"));
+        }
+
+        assert!(!log.exists());
+    }
+
+    #[test]
+    fn encoding() {
+        assert_eq!(encode("-12.5_~"), "-12.5_~");
+        assert_eq!(encode("a&b=c+d 50%"), "a%26b%3Dc%2Bd%2050%25");
+        assert_eq!(encode("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn submits() {
+        let (url, server) = mock::serve(vec![mock::respond(200, TOO_HIGH), mock::respond(200, CORRECT)]);
+        let log = env::temp_dir().join(format!("aoc-submit-{}.txt", process::id()));
+
+        let config = Config {
+            session: Some(String::from("secret")),
+            url,
+            backend: None,
+        };
+
+        let puzzle = Puzzle { year: 2024, day: 1, part: Part::One };
+
+        let _ = fs::remove_file(&log);
+
+        assert_eq!(submit_answer(&Tcp, &config, puzzle, "500", &log, 1000).unwrap(), Verdict::TooHigh);
+        assert!(submit_answer(&Tcp, &config, puzzle, "400", &log, 1030).unwrap_err().to_string().contains("try again in 30s"));
+        assert!(submit_answer(&Tcp, &config, puzzle, "500", &log, 1060).unwrap_err().to_string().contains("already submitted"));
+        assert_eq!(submit_answer(&Tcp, &config, puzzle, "400", &log, 1060).unwrap(), Verdict::Correct);
+
+        assert_eq!(fs::read_to_string(&log).unwrap(), "1000 1 high 60 500\n1060 1 correct 0 400\n");
+
+        let requests = server.join().unwrap();
+
+        assert!(requests[0].starts_with("POST /2024/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=500"));
+
+        fs::remove_file(&log).unwrap();
+    }
+}
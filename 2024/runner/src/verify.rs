@@ -0,0 +1,129 @@
+use std::time::{Duration, Instant};
+
+use aoc::answers::{self, Answers};
+use aoc::{Answer, Day, Part};
+
+use crate::args::Args;
+use crate::registry::DAYS;
+use crate::input;
+
+
+enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+    Error(String),
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Summary {
+    fn report(&mut self, day: &Day, part: Option<Part>, name: &str, elapsed: Duration, status: Status) {
+        let part = part.map(|part| part.to_string()).unwrap_or(String::from("-"));
+
+        let (label, detail) = match status {
+            Status::Pass => { self.passed += 1; ("pass", String::new()) },
+            Status::Fail(detail) => { self.failed += 1; ("FAIL", detail) },
+            Status::Missing => { self.missing += 1; ("missing", String::from("no known answer")) },
+            Status::Error(detail) => { self.failed += 1; ("ERROR", detail) },
+        };
+
+        println!("{:<8} {} day {:<2} part {} {:<12} {:>10}  {}", label, day.year, day.day, part, name, aoc::time::format(elapsed), detail);
+    }
+}
+
+fn check(answer: &Answer, expected: Option<&str>) -> Status {
+    match expected {
+        Some(expected) if answer.to_string() == expected => Status::Pass,
+        Some(expected) => Status::Fail(format!("got {}, expected {}", answer, expected)),
+        None => Status::Missing,
+    }
+}
+
+pub fn verify(args: &Args) -> aoc::Result<()> {
+    let (year, day) = args.selection()?;
+    let record = args.switch("record");
+
+    let mut summary = Summary::default();
+
+    for day in aoc::registry::select(DAYS, year, day)? {
+        let path = day.dir(&input::root()).join(answers::FILE);
+        let mut answers = Answers::load(&path)?;
+        let mut changed = false;
+
+        let names = match args.option("input") {
+            Some(name) => vec![name.to_string()],
+            None => {
+                let mut names = answers.inputs().iter().map(|name| name.to_string()).collect::<Vec<String>>();
+
+                if !names.iter().any(|name| name == input::DEFAULT) {
+                    names.insert(0, input::DEFAULT.to_string());
+                }
+
+                names
+            },
+        };
+
+        for name in names {
+            let input = match input::load(day, Some(&name)) {
+                Ok(input) => input,
+                Err(err) => {
+                    summary.report(day, None, &name, Duration::ZERO, Status::Error(err.to_string()));
+
+                    continue;
+                },
+            };
+
+            let start = Instant::now();
+
+            let solution = match (day.parse)(&input.content) {
+                Ok(solution) => solution,
+                Err(err) => {
+                    summary.report(day, None, &name, start.elapsed(), Status::Error(aoc::error::with_file(err, &input.name).to_string()));
+
+                    continue;
+                },
+            };
+
+            for part in Part::ALL {
+                let start = Instant::now();
+                let result = part.solve(solution.as_ref());
+                let elapsed = start.elapsed();
+
+                let status = match result {
+                    Ok(answer) => {
+                        let status = check(&answer, answers.get(&name, part));
+
+                        if record && matches!(status, Status::Missing) {
+                            answers.set(&name, part, &answer.to_string());
+
+                            changed = true;
+                        }
+
+                        status
+                    },
+                    Err(err) => Status::Error(err.to_string()),
+                };
+
+                summary.report(day, Some(part), &name, elapsed, status);
+            }
+        }
+
+        if changed {
+            answers.save(&path)?;
+        }
+    }
+
+    println!("\n{} passed, {} failed, {} missing", summary.passed, summary.failed, summary.missing);
+
+    if summary.failed > 0 {
+        return Err(format!("{} answers did not match", summary.failed).into());
+    }
+
+    Ok(())
+}